@@ -0,0 +1,151 @@
+use crate::days::{self, InputSource};
+use crate::solution::Part;
+use crate::{parse_err, Error, Result};
+
+/// The path to the manifest of expected answers.
+pub const MANIFEST_PATH: &str = "data/answers";
+
+/// The name of the manifest of expected answers within the examples directory of a day.
+pub const EXAMPLES_MANIFEST: &str = "answers";
+
+/// Get the path to the directory holding the worked examples of a day and their answers.
+pub fn examples_dir(day: usize) -> String {
+    format!("data/day{:02}/examples", day)
+}
+
+/// The expected answer to one part of a puzzle for a given input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub day: usize,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+}
+
+impl std::str::FromStr for ExpectedAnswer {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        // 1 1 data/day01/input 74
+        // 25 2 data/day25/input Merry Christmas!
+        let tokens: Vec<&str> = s.trim().splitn(4, char::is_whitespace).collect();
+
+        if tokens.len() != 4 {
+            return Err(parse_err!("Invalid number of tokens: '{}'", s));
+        }
+
+        let day = tokens[0].parse()?;
+        let part = tokens[1].parse()?;
+        let input = tokens[2].to_owned();
+        let expected = tokens[3].trim().to_owned();
+
+        Ok(ExpectedAnswer {
+            day,
+            part,
+            input,
+            expected,
+        })
+    }
+}
+
+/// Read a manifest of expected answers, skipping blank lines and `#` comments.
+pub fn read_manifest(path: &str) -> Result<Vec<ExpectedAnswer>> {
+    crate::util::read_to_string(path)?
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map(|l| l.parse())
+        .collect()
+}
+
+/// Read the expected answers to the worked examples of a day, if it has any.
+///
+/// Example inputs are kept next to their manifest in `examples_dir`, which lists answers in the
+/// same format as the main manifest.
+pub fn read_examples(day: usize) -> Result<Vec<ExpectedAnswer>> {
+    let path = format!("{}/{}", examples_dir(day), EXAMPLES_MANIFEST);
+    if !std::path::Path::new(&path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let examples = read_manifest(&path)?;
+    if let Some(e) = examples.iter().find(|e| e.day != day) {
+        return Err(parse_err!("Example for day {} listed for day {}", e.day, day).in_file(&path));
+    }
+
+    Ok(examples)
+}
+
+/// List the example inputs of a day, i.e. all files in its examples directory except the
+/// manifest.
+pub fn example_inputs(day: usize) -> Result<Vec<String>> {
+    let dir = examples_dir(day);
+    if !std::path::Path::new(&dir).is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut inputs = Vec::new();
+    for entry in std::fs::read_dir(&dir).map_err(|e| Error::from(e).in_file(&dir))? {
+        let entry = entry.map_err(|e| Error::from(e).in_file(&dir))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        if name != EXAMPLES_MANIFEST {
+            inputs.push(format!("{}/{}", dir, name));
+        }
+    }
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+/// An answer produced by a solution that differs from the expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: usize,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "day {:02} part {} ({}): expected '{}', got '{}'",
+            self.day, self.part, self.input, self.expected, self.actual
+        )
+    }
+}
+
+/// Run the solution of a day on every input listed for it and collect all answers that differ
+/// from the expected ones.
+pub fn check_day(manifest: &[ExpectedAnswer], day: usize) -> Result<Vec<Mismatch>> {
+    let expected: Vec<&ExpectedAnswer> = manifest.iter().filter(|e| e.day == day).collect();
+
+    let mut inputs: Vec<&str> = expected.iter().map(|e| &e.input[..]).collect();
+    inputs.sort();
+    inputs.dedup();
+
+    let mut mismatches = Vec::new();
+    for input in inputs {
+        let source = InputSource::File {
+            path: input.to_owned(),
+        };
+        let answers = days::run_from(day, &source)?.answers;
+
+        for e in expected.iter().filter(|e| e.input == input) {
+            let actual = answers.get(e.part);
+
+            if actual != e.expected {
+                mismatches.push(Mismatch {
+                    day,
+                    part: e.part,
+                    input: input.to_owned(),
+                    expected: e.expected.clone(),
+                    actual: actual.to_owned(),
+                });
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
@@ -0,0 +1,255 @@
+use crate::days;
+use crate::solution::Timings;
+use crate::util::read_to_string;
+use crate::{parse_err, state_err, Error, Result};
+
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// A step in solving a puzzle that is timed separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Step {
+    pub const ALL: [Step; 3] = [Step::Parse, Step::PartOne, Step::PartTwo];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::PartOne => "part_one",
+            Step::PartTwo => "part_two",
+        }
+    }
+
+    /// Get the time this step took in a single run.
+    pub fn of(&self, timings: &Timings) -> Duration {
+        match self {
+            Step::Parse => timings.parse,
+            Step::PartOne => timings.part_one,
+            Step::PartTwo => timings.part_two,
+        }
+    }
+}
+
+impl std::str::FromStr for Step {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Step::ALL
+            .iter()
+            .find(|step| step.name() == s)
+            .copied()
+            .ok_or_else(|| parse_err!("Invalid step: '{}'", s))
+    }
+}
+
+/// Summary statistics over repeated measurements of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarize a list of measurements, or None if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        if n == 0 {
+            return None;
+        }
+
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[n - 1],
+        })
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "min": self.min.as_nanos() as u64,
+            "median": self.median.as_nanos() as u64,
+            "max": self.max.as_nanos() as u64,
+        })
+    }
+
+    fn from_json(v: &Value) -> Result<Stats> {
+        let get = |key: &str| -> Result<Duration> {
+            v.get(key)
+                .and_then(|n| n.as_u64())
+                .map(Duration::from_nanos)
+                .ok_or_else(|| parse_err!("Missing or invalid '{}' in {}", key, v))
+        };
+
+        Ok(Stats {
+            min: get("min")?,
+            median: get("median")?,
+            max: get("max")?,
+        })
+    }
+}
+
+/// Timing statistics for every step of solving the puzzle of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub day: usize,
+    pub runs: usize,
+    pub stats: BTreeMap<Step, Stats>,
+}
+
+impl Benchmark {
+    /// Describe the statistics of each step as a JSON record for consumption by other tools.
+    pub fn to_json(&self, input: &str) -> Vec<Value> {
+        self.stats
+            .iter()
+            .map(|(step, stats)| {
+                json!({
+                    "day": self.day,
+                    "step": step.name(),
+                    "runs": self.runs,
+                    "min_ns": stats.min.as_nanos() as u64,
+                    "median_ns": stats.median.as_nanos() as u64,
+                    "max_ns": stats.max.as_nanos() as u64,
+                    "input": input,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Solve the puzzle of a day repeatedly and summarize how long every step took.
+pub fn benchmark(day: usize, input: &str, runs: usize) -> Result<Benchmark> {
+    if runs == 0 {
+        return Err(state_err!("Need at least one run to benchmark"));
+    }
+
+    let mut timings = Vec::new();
+    for _ in 0..runs {
+        timings.push(days::run(day, input)?.timings);
+    }
+
+    let mut stats = BTreeMap::new();
+    for step in Step::ALL.iter() {
+        let samples: Vec<Duration> = timings.iter().map(|t| step.of(t)).collect();
+        if let Some(s) = Stats::from_samples(&samples) {
+            stats.insert(*step, s);
+        }
+    }
+
+    Ok(Benchmark { day, runs, stats })
+}
+
+/// Previously saved timing statistics to compare new benchmarks against.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Baseline {
+    stats: BTreeMap<(usize, Step), Stats>,
+}
+
+impl Baseline {
+    /// Get the baseline statistics for a step of a day, if there are any.
+    pub fn get(&self, day: usize, step: Step) -> Option<&Stats> {
+        self.stats.get(&(day, step))
+    }
+
+    /// Record the results of a benchmark, replacing earlier results for the same day.
+    pub fn update(&mut self, benchmark: &Benchmark) {
+        self.stats.retain(|(day, _), _| *day != benchmark.day);
+
+        for (step, stats) in benchmark.stats.iter() {
+            self.stats.insert((benchmark.day, *step), *stats);
+        }
+    }
+
+    /// Read a baseline from a JSON file holding nanosecond timings keyed by day and step.
+    pub fn read(path: &str) -> Result<Baseline> {
+        let data: Value = serde_json::from_str(&read_to_string(path)?)?;
+
+        let days = data
+            .as_object()
+            .ok_or_else(|| parse_err!("Baseline {} is not a JSON object", path))?;
+
+        let mut stats = BTreeMap::new();
+        for (day, steps) in days.iter() {
+            let day: usize = day.parse()?;
+
+            let steps = steps
+                .as_object()
+                .ok_or_else(|| parse_err!("Baseline for day {} is not a JSON object", day))?;
+
+            for (step, s) in steps.iter() {
+                stats.insert((day, step.parse()?), Stats::from_json(s)?);
+            }
+        }
+
+        Ok(Baseline { stats })
+    }
+
+    /// Write the baseline to a JSON file.
+    pub fn write(&self, path: &str) -> Result<()> {
+        let mut days = serde_json::Map::new();
+        for ((day, step), stats) in self.stats.iter() {
+            let steps = days
+                .entry(day.to_string())
+                .or_insert_with(|| Value::Object(serde_json::Map::new()));
+
+            if let Value::Object(steps) = steps {
+                steps.insert(step.name().to_owned(), stats.to_json());
+            }
+        }
+
+        let data = serde_json::to_string_pretty(&Value::Object(days))?;
+        std::fs::write(path, data + "\n")?;
+
+        Ok(())
+    }
+}
+
+/// Format a duration with a unit that keeps the number readable.
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+
+    if ns < 1_000 {
+        format!("{}ns", ns)
+    } else if ns < 1_000_000 {
+        format!("{:.1}µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1}ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2}s", ns as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,286 @@
+use aoc2015::bench::{benchmark, format_duration, Baseline, Benchmark};
+use aoc2015::board::animation::Animation;
+use aoc2015::days::day07::Day07;
+use aoc2015::days::day18::{self, Day18};
+use aoc2015::days::{self, InputSource, FIRST_DAY, LAST_DAY};
+use aoc2015::solution::Solution;
+use aoc2015::{parse_err, Result};
+
+/// Parse a day selection like `7`, `3-9` or `all` into a list of days.
+fn parse_days(spec: &str) -> Result<Vec<usize>> {
+    if spec == "all" {
+        return Ok((FIRST_DAY..=LAST_DAY).collect());
+    }
+
+    let (from, to) = if let Some(ofs) = spec.find('-') {
+        (spec[..ofs].parse()?, spec[ofs + 1..].parse()?)
+    } else {
+        let day = spec.parse()?;
+        (day, day)
+    };
+
+    if from < FIRST_DAY || to > LAST_DAY || from > to {
+        return Err(parse_err!(
+            "Invalid day selection '{}', days range from {} to {}",
+            spec,
+            FIRST_DAY,
+            LAST_DAY
+        ));
+    }
+
+    Ok((from..=to).collect())
+}
+
+const USAGE: &str = "Usage: aoc [OPTIONS] [DAY | FROM-TO | all]...
+
+Options:
+  -i, --input PATH          read the puzzle input from PATH instead of data/dayNN/input,
+                            use - to read from standard input (needs a single day)
+  -j, --json                print one JSON record per day and part instead of text
+  -b, --bench               time parsing and both parts instead of printing answers
+  -r, --runs N              number of runs per day in benchmark mode (default: 10)
+      --baseline PATH       compare benchmark medians against a saved baseline
+      --save-baseline PATH  save the benchmark results as a baseline
+      --dot                 print the circuit of day 7 as a Graphviz DOT graph instead,
+                            labelled with the signals if the circuit can be solved
+                            (selects day 7 if no day is given)
+      --animate             draw the lights of day 18 in the terminal while they animate,
+                            first as in part one, then with the corners stuck on
+                            (selects day 18 if no day is given)";
+
+/// The options given on the command line.
+struct Options {
+    days: Vec<usize>,
+    source: InputSource,
+    json: bool,
+    bench: bool,
+    runs: usize,
+    baseline: Option<String>,
+    save_baseline: Option<String>,
+    dot: bool,
+    animate: bool,
+}
+
+impl Options {
+    fn from_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>> {
+        let mut options = Options {
+            days: Vec::new(),
+            source: InputSource::Default,
+            json: false,
+            bench: false,
+            runs: 10,
+            baseline: None,
+            save_baseline: None,
+            dot: false,
+            animate: false,
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| parse_err!("Missing value for {}\n\n{}", arg, USAGE))
+            };
+
+            match &arg[..] {
+                "-i" | "--input" => options.source = value()?.parse()?,
+                "-j" | "--json" => options.json = true,
+                "-b" | "--bench" => options.bench = true,
+                "-r" | "--runs" => options.runs = value()?.parse()?,
+                "--baseline" => options.baseline = Some(value()?),
+                "--save-baseline" => options.save_baseline = Some(value()?),
+                "--dot" => options.dot = true,
+                "--animate" => options.animate = true,
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    return Ok(None);
+                }
+                _ => options.days.extend(parse_days(&arg)?),
+            }
+        }
+
+        if options.days.is_empty() {
+            options.days = if options.dot {
+                vec![7]
+            } else if options.animate {
+                vec![18]
+            } else {
+                parse_days("all")?
+            };
+        }
+
+        if options.source != InputSource::Default && options.days.len() != 1 {
+            return Err(parse_err!(
+                "An input path can only be given when running a single day\n\n{}",
+                USAGE
+            ));
+        }
+
+        if options.dot && options.days != [7] {
+            return Err(parse_err!(
+                "A DOT graph can only be drawn for day 7\n\n{}",
+                USAGE
+            ));
+        }
+
+        if options.animate && options.days != [18] {
+            return Err(parse_err!(
+                "Only the lights of day 18 can be animated\n\n{}",
+                USAGE
+            ));
+        }
+
+        Ok(Some(options))
+    }
+}
+
+fn print_answers(options: &Options) -> Result<()> {
+    for day in options.days.iter().copied() {
+        let report = days::run_from(day, &options.source)?;
+
+        if options.json {
+            for record in report.to_json(day, &options.source.describe(day)) {
+                println!("{}", record);
+            }
+        } else {
+            println!("Day {:02} ({})", day, options.source.describe(day));
+            println!("  Part one: {}", report.answers.part_one);
+            println!("  Part two: {}", report.answers.part_two);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_dot(options: &Options) -> Result<()> {
+    let circuit = Day07::parse(&options.source.read(7)?)
+        .map_err(|e| e.in_file(&options.source.describe(7)))?;
+
+    // a circuit that cannot be solved is still worth drawing, e.g. to find a cycle
+    let values = circuit.solve().ok();
+    print!("{}", circuit.to_dot(values.as_ref()));
+
+    Ok(())
+}
+
+fn animate_lights(options: &Options) -> Result<()> {
+    let board = Day18::parse(&options.source.read(18)?)
+        .map_err(|e| e.in_file(&options.source.describe(18)))?;
+
+    let mut animation = Animation::stdout().with_delay(std::time::Duration::from_millis(50));
+    for stuck in [false, true] {
+        let on = day18::animate(&board, day18::STEPS, stuck, &mut animation)?;
+        println!("Lights on: {}", on);
+    }
+
+    Ok(())
+}
+
+fn print_benchmark(bench: &Benchmark, input: &str, baseline: Option<&Baseline>) {
+    println!("Day {:02} ({}, {} runs)", bench.day, input, bench.runs);
+
+    for (step, stats) in bench.stats.iter() {
+        print!(
+            "  {:8}  min {:>9}  median {:>9}  max {:>9}",
+            step.name(),
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.max),
+        );
+
+        if let Some(base) = baseline.and_then(|b| b.get(bench.day, *step)) {
+            let change = (stats.median.as_secs_f64() / base.median.as_secs_f64() - 1.0) * 100.0;
+            print!(
+                "  baseline {:>9} ({:+.1}%)",
+                format_duration(base.median),
+                change
+            );
+        }
+
+        println!();
+    }
+}
+
+fn print_benchmarks(options: &Options) -> Result<()> {
+    let baseline = match &options.baseline {
+        Some(path) => Some(Baseline::read(path)?),
+        None => None,
+    };
+
+    let mut new_baseline = match &options.save_baseline {
+        Some(path) if std::path::Path::new(path).exists() => Baseline::read(path)?,
+        _ => Baseline::default(),
+    };
+
+    for day in options.days.iter().copied() {
+        let input = options.source.read(day)?;
+        let bench = benchmark(day, &input, options.runs)
+            .map_err(|e| e.in_file(&options.source.describe(day)))?;
+
+        if options.json {
+            for record in bench.to_json(&options.source.describe(day)) {
+                println!("{}", record);
+            }
+        } else {
+            print_benchmark(&bench, &options.source.describe(day), baseline.as_ref());
+        }
+
+        new_baseline.update(&bench);
+    }
+
+    if let Some(path) = &options.save_baseline {
+        new_baseline.write(path)?;
+        eprintln!("Saved baseline to {}", path);
+    }
+
+    Ok(())
+}
+
+fn run() -> Result<()> {
+    let options = match Options::from_args(std::env::args().skip(1))? {
+        Some(options) => options,
+        None => return Ok(()),
+    };
+
+    if options.dot {
+        print_dot(&options)
+    } else if options.animate {
+        animate_lights(&options)
+    } else if options.bench {
+        print_benchmarks(&options)
+    } else {
+        print_answers(&options)
+    }
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse_args(args: &str) -> Result<Options> {
+        let args = args.split_whitespace().map(|a| a.to_owned());
+        Ok(Options::from_args(args)?.unwrap())
+    }
+
+    #[test]
+    fn test_options() -> Result<()> {
+        assert_eq!(parse_args("")?.days, parse_days("all")?);
+        assert_eq!(parse_args("--dot")?.days, vec![7]);
+        assert_eq!(parse_args("-i - --dot")?.days, vec![7]);
+        assert_eq!(parse_args("--animate")?.days, vec![18]);
+        assert_eq!(parse_args("--animate 18")?.days, vec![18]);
+
+        assert!(parse_args("--dot 8").is_err());
+        assert!(parse_args("--animate 7").is_err());
+        assert!(parse_args("--dot --animate").is_err());
+
+        Ok(())
+    }
+}
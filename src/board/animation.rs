@@ -0,0 +1,112 @@
+use super::{Grid, Tile};
+use crate::Result;
+
+use std::io::Write;
+use std::time::Duration;
+
+/// Draws the states of an evolving board to an ANSI terminal, redrawing only the tiles that
+/// changed since the previous frame.
+///
+/// The first frame, and any frame in which the bounds of the board changed, clears the screen
+/// and draws the whole board. The cursor is left on the line below the board.
+pub struct Animation<G, W> {
+    writer: W,
+    previous: Option<G>,
+    delay: Duration,
+}
+
+impl<G: Grid + Clone> Animation<G, std::io::Stdout> {
+    /// Animate on standard output.
+    pub fn stdout() -> Self {
+        Animation::new(std::io::stdout())
+    }
+}
+
+impl<G: Grid + Clone, W: Write> Animation<G, W> {
+    pub fn new(writer: W) -> Self {
+        Animation {
+            writer,
+            previous: None,
+            delay: Duration::default(),
+        }
+    }
+
+    /// Pause for a while after drawing every frame.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Draw the next state of the board.
+    pub fn frame(&mut self, grid: &G) -> Result<()>
+    where
+        G::Tile: Tile,
+    {
+        let bounds = grid.bounds();
+
+        match &self.previous {
+            Some(previous) if previous.bounds() == bounds => {
+                if let Some(bounds) = bounds {
+                    for change in previous.diff(grid) {
+                        write!(
+                            self.writer,
+                            "\x1b[{};{}H{}",
+                            change.pos.i - bounds.min.i + 1,
+                            change.pos.j - bounds.min.j + 1,
+                            change.new.to_char()
+                        )?;
+                    }
+                }
+            }
+            _ => write!(self.writer, "\x1b[2J\x1b[H{}", grid.render())?,
+        }
+
+        let height = bounds.map(|b| b.height()).unwrap_or(0);
+        write!(self.writer, "\x1b[{};1H", height + 1)?;
+        self.writer.flush()?;
+
+        if self.delay > Duration::default() {
+            std::thread::sleep(self.delay);
+        }
+
+        self.previous = Some(grid.clone());
+
+        Ok(())
+    }
+
+    /// Stop animating and get the writer back.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::automaton::{Automaton, Rule};
+    use crate::board::{DenseBoard, Rect, Topology};
+
+    #[test]
+    fn test_animation() -> Result<()> {
+        let mut board = DenseBoard::with_bounds(Rect::new((0, 0).into(), (2, 2).into()))
+            .with_topology(Topology::Bounded { edge: false });
+        for j in 0..3 {
+            board.set(&(1, j).into(), true);
+        }
+
+        let life = Automaton::new(Rule::conway());
+        let mut animation = Animation::new(Vec::new());
+        animation.frame(&board)?;
+        animation.frame(&life.step(&board))?;
+
+        let out = String::from_utf8(animation.into_inner()).unwrap();
+        assert_eq!(
+            out,
+            "\x1b[2J\x1b[H...\n###\n...\n\x1b[4;1H\
+             \x1b[1;2H#\x1b[2;1H.\x1b[2;3H.\x1b[3;2H#\x1b[4;1H"
+        );
+
+        Ok(())
+    }
+}
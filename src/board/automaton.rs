@@ -0,0 +1,450 @@
+use super::nd::{BoardN, PosN};
+use super::{Grid, Position, Rect, Topology};
+use crate::{parse_err, Error, Result};
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A tile of a cellular automaton, which is either alive or dead.
+pub trait Cell: Copy {
+    fn is_alive(&self) -> bool;
+
+    fn from_alive(alive: bool) -> Self;
+}
+
+impl Cell for bool {
+    fn is_alive(&self) -> bool {
+        *self
+    }
+
+    fn from_alive(alive: bool) -> Self {
+        alive
+    }
+}
+
+/// Decides whether a cell is alive in the next generation, given whether it is alive now and how
+/// many of its neighbours are, i.e. up to eight on a two-dimensional board and up to 26 or 80 on
+/// a `BoardN` in three or four dimensions.
+pub enum Rule {
+    /// Dead cells with a neighbour count in `birth` come alive, live cells with a count in
+    /// `survival` stay alive, all others die.
+    BirthSurvival {
+        birth: Vec<usize>,
+        survival: Vec<usize>,
+    },
+
+    /// Any other rule, as a function of the state of a cell and its number of live neighbours.
+    Custom(Box<dyn Fn(bool, usize) -> bool>),
+}
+
+impl std::fmt::Debug for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            Rule::BirthSurvival { birth, survival } => {
+                // counts of 10 or more need commas, see `Rule::parse_for`
+                let commas = birth.iter().chain(survival).any(|n| *n > 9);
+                let digits = |counts: &[usize]| {
+                    let counts: Vec<String> = counts.iter().map(|n| n.to_string()).collect();
+                    match counts.len() {
+                        0 | 1 if commas => counts.concat() + ",",
+                        _ if commas => counts.join(","),
+                        _ => counts.concat(),
+                    }
+                };
+                write!(f, "B{}/S{}", digits(birth), digits(survival))
+            }
+            Rule::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+/// Parses a rule in B/S notation for a two-dimensional board, like `B3/S23`, see
+/// `Rule::parse_for` for boards with more neighbours.
+impl std::str::FromStr for Rule {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Rule::parse_for(s, 8)
+    }
+}
+
+impl Rule {
+    /// Conway's game of life, B3/S23.
+    pub fn conway() -> Rule {
+        Rule::BirthSurvival {
+            birth: vec![3],
+            survival: vec![2, 3],
+        }
+    }
+
+    /// Parse a rule in B/S notation for cells with up to `max_neighbors` neighbours.
+    ///
+    /// Counts are single digits like in `B3/S23`, or separated by commas for counts of 10 or more
+    /// like in `B5,13/S12,`, where a trailing comma marks a single count.
+    pub fn parse_for(s: &str, max_neighbors: usize) -> Result<Rule> {
+        let counts = |part: &str, prefix: char| -> Result<Vec<usize>> {
+            let part = part
+                .strip_prefix(prefix)
+                .ok_or_else(|| parse_err!("Expected '{}' in rule '{}'", prefix, s))?;
+
+            let counts: Vec<&str> = if part.contains(',') {
+                part.split(',').filter(|c| !c.is_empty()).collect()
+            } else {
+                part.char_indices()
+                    .map(|(i, c)| &part[i..i + c.len_utf8()])
+                    .collect()
+            };
+
+            counts
+                .into_iter()
+                .map(|c| match c.parse() {
+                    Ok(n) if n <= max_neighbors => Ok(n),
+                    _ => Err(parse_err!(
+                        "Invalid neighbour count '{}' in rule '{}'",
+                        c,
+                        s
+                    )),
+                })
+                .collect()
+        };
+
+        let parts: Vec<&str> = s.trim().split('/').collect();
+        match parts[..] {
+            [b, s] => Ok(Rule::BirthSurvival {
+                birth: counts(b, 'B')?,
+                survival: counts(s, 'S')?,
+            }),
+            _ => Err(parse_err!("Expected a rule like 'B3/S23', got '{}'", s)),
+        }
+    }
+
+    pub fn custom<F: Fn(bool, usize) -> bool + 'static>(f: F) -> Rule {
+        Rule::Custom(Box::new(f))
+    }
+
+    pub fn next(&self, alive: bool, neighbors: usize) -> bool {
+        match self {
+            Rule::BirthSurvival { birth, survival } => {
+                if alive {
+                    survival.contains(&neighbors)
+                } else {
+                    birth.contains(&neighbors)
+                }
+            }
+            Rule::Custom(f) => f(alive, neighbors),
+        }
+    }
+}
+
+/// A board of any number of dimensions that an automaton can run on.
+///
+/// Implemented for every two-dimensional `Grid` and for the N-dimensional `BoardN`.
+pub trait Space: Clone {
+    type Pos: Copy + Ord + Hash;
+    type Tile: Cell;
+
+    /// Get the cell at a position, following the topology of the board.
+    fn cell(&self, pos: &Self::Pos) -> Self::Tile;
+
+    fn set_cell(&mut self, pos: &Self::Pos, tile: Self::Tile);
+
+    /// Get the positions whose cells may change in the next generation, or None if the board is
+    /// empty.
+    fn step_region(&self) -> Option<Vec<Self::Pos>>;
+
+    /// Whether a position lies within the current extent of the board.
+    fn within(&self, pos: &Self::Pos) -> bool;
+
+    /// Count the live cells among the neighbours of a position.
+    fn live_neighbors(&self, pos: &Self::Pos) -> usize;
+
+    /// Get the positions of all stored live cells, in order.
+    fn live_cells(&self) -> Vec<Self::Pos>;
+}
+
+impl<G: Grid + Clone> Space for G
+where
+    G::Tile: Cell,
+{
+    type Pos = Position;
+    type Tile = G::Tile;
+
+    fn cell(&self, pos: &Position) -> G::Tile {
+        self.lookup(pos)
+    }
+
+    fn set_cell(&mut self, pos: &Position, tile: G::Tile) {
+        self.set(pos, tile);
+    }
+
+    fn step_region(&self) -> Option<Vec<Position>> {
+        let bounds = self.bounds()?;
+
+        let region = match self.topology() {
+            Topology::Infinite => {
+                Rect::new(bounds.min + (-1, -1).into(), bounds.max + (1, 1).into())
+            }
+            _ => bounds,
+        };
+
+        Some(region.positions().collect())
+    }
+
+    fn within(&self, pos: &Position) -> bool {
+        self.bounds().map(|b| b.contains(pos)).unwrap_or(false)
+    }
+
+    fn live_neighbors(&self, pos: &Position) -> usize {
+        self.count_neighbors(pos, |t| t.is_alive())
+    }
+
+    fn live_cells(&self) -> Vec<Position> {
+        let mut cells: Vec<Position> = self
+            .iter()
+            .filter(|(_, t)| t.is_alive())
+            .map(|(pos, _)| pos)
+            .collect();
+        cells.sort();
+        cells
+    }
+}
+
+impl<T: Cell + Default + PartialEq, const D: usize> Space for BoardN<T, D> {
+    type Pos = PosN<D>;
+    type Tile = T;
+
+    fn cell(&self, pos: &Self::Pos) -> T {
+        self.get(pos)
+    }
+
+    fn set_cell(&mut self, pos: &Self::Pos, tile: T) {
+        self.set(pos, tile);
+    }
+
+    fn step_region(&self) -> Option<Vec<Self::Pos>> {
+        Some(self.bounds()?.expand(1).positions().collect())
+    }
+
+    fn within(&self, pos: &Self::Pos) -> bool {
+        self.bounds().map(|b| b.contains(pos)).unwrap_or(false)
+    }
+
+    fn live_neighbors(&self, pos: &Self::Pos) -> usize {
+        self.count_neighbors(pos, |t| t.is_alive())
+    }
+
+    fn live_cells(&self) -> Vec<Self::Pos> {
+        let mut cells: Vec<Self::Pos> = self
+            .iter()
+            .filter(|(_, t)| t.is_alive())
+            .map(|(pos, _)| pos)
+            .collect();
+        cells.sort();
+        cells
+    }
+}
+
+/// How the states of an automaton developed over a number of generations.
+#[derive(Debug, Clone)]
+pub enum Evolution<G> {
+    /// The state stopped changing, first reaching its final value after `generation` generations.
+    FixedPoint { generation: usize, grid: G },
+
+    /// The state after `start` generations recurs every `period` generations.
+    Cycle {
+        start: usize,
+        period: usize,
+        grid: G,
+    },
+
+    /// Neither happened within the limit of generations. Holds the last state.
+    Unsettled { grid: G },
+}
+
+/// A cellular automaton with a rule and cells pinned to a fixed state.
+///
+/// It runs on any `Space`. Neighbours are looked up according to the topology of the board, and
+/// boards with an infinite topology grow wherever cells come alive.
+#[derive(Debug)]
+pub struct Automaton<T, P = Position> {
+    rule: Rule,
+    pinned: Vec<(P, T)>,
+}
+
+impl<T: Cell, P: Copy + Ord + Hash> Automaton<T, P> {
+    pub fn new(rule: Rule) -> Self {
+        Automaton {
+            rule,
+            pinned: Vec::new(),
+        }
+    }
+
+    /// Keep a cell in the same state in every generation, including the first.
+    pub fn pin(mut self, pos: P, tile: T) -> Self {
+        self.pinned.push((pos, tile));
+        self
+    }
+
+    fn apply_pins<G: Space<Pos = P, Tile = T>>(&self, grid: &mut G) {
+        for (pos, tile) in self.pinned.iter() {
+            grid.set_cell(pos, *tile);
+        }
+    }
+
+    /// Compute the next generation.
+    pub fn step<G: Space<Pos = P, Tile = T>>(&self, grid: &G) -> G {
+        let region = match grid.step_region() {
+            Some(region) => region,
+            None => return grid.clone(),
+        };
+
+        let mut next = grid.clone();
+        for pos in region {
+            let alive = grid.cell(&pos).is_alive();
+            let neighbors = grid.live_neighbors(&pos);
+
+            let will_live = self.rule.next(alive, neighbors);
+            if will_live != alive || grid.within(&pos) {
+                next.set_cell(&pos, T::from_alive(will_live));
+            }
+        }
+
+        self.apply_pins(&mut next);
+
+        next
+    }
+
+    /// Compute the state after a number of generations.
+    pub fn run<G: Space<Pos = P, Tile = T>>(&self, grid: &G, generations: usize) -> G {
+        let mut grid = grid.clone();
+        self.apply_pins(&mut grid);
+
+        for _ in 0..generations {
+            grid = self.step(&grid);
+        }
+
+        grid
+    }
+
+    /// Run for at most a number of generations, stopping early once a state repeats.
+    ///
+    /// States are compared by the positions of their live cells.
+    pub fn evolve<G: Space<Pos = P, Tile = T>>(
+        &self,
+        grid: &G,
+        max_generations: usize,
+    ) -> Evolution<G> {
+        let mut grid = grid.clone();
+        self.apply_pins(&mut grid);
+
+        let mut seen = HashMap::new();
+        seen.insert(grid.live_cells(), 0);
+
+        for generation in 1..=max_generations {
+            grid = self.step(&grid);
+
+            if let Some(start) = seen.insert(grid.live_cells(), generation) {
+                let period = generation - start;
+                return if period == 1 {
+                    Evolution::FixedPoint {
+                        generation: start,
+                        grid,
+                    }
+                } else {
+                    Evolution::Cycle {
+                        start,
+                        period,
+                        grid,
+                    }
+                };
+            }
+        }
+
+        Evolution::Unsettled { grid }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::{Board, DenseBoard};
+
+    #[test]
+    fn test_rule() -> Result<()> {
+        let rule: Rule = "B36/S23".parse()?;
+        assert!(rule.next(false, 6));
+        assert!(!rule.next(true, 6));
+        assert_eq!(format!("{:?}", rule), "B36/S23");
+
+        assert!("B3S23".parse::<Rule>().is_err());
+        assert!("B9/S23".parse::<Rule>().is_err());
+
+        let rule = Rule::parse_for("B5,6,7/S5,6", 26)?;
+        assert!(rule.next(false, 7) && rule.next(true, 5) && !rule.next(true, 7));
+        assert_eq!(format!("{:?}", rule), "B567/S56");
+
+        let rule = Rule::parse_for("B12/S10,", 26)?;
+        assert_eq!(format!("{:?}", rule), "B1,2/S10,");
+        let rule = Rule::parse_for("B12,/S10,26", 26)?;
+        assert!(rule.next(false, 12) && rule.next(true, 26));
+        assert_eq!(format!("{:?}", rule), "B12,/S10,26");
+        let rule = Rule::parse_for(&format!("{:?}", rule), 26)?;
+        assert!(rule.next(false, 12) && !rule.next(false, 1));
+
+        assert!(Rule::parse_for("B27,/S", 26).is_err());
+        assert!(Rule::parse_for("B1,x/S", 26).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_automaton() {
+        // a blinker oscillates with period 2
+        let mut board = Board::new();
+        for j in 0..3 {
+            board.set(&(0, j).into(), true);
+        }
+
+        let life = Automaton::new(Rule::conway());
+        let next = life.step(&board);
+        assert!(next.get(&(-1, 1).into()) && next.get(&(1, 1).into()));
+        assert!(!next.get(&(0, 0).into()));
+
+        match life.evolve(&board, 10) {
+            Evolution::Cycle { start, period, .. } => assert_eq!((start, period), (0, 2)),
+            e => panic!("Unexpected evolution {:?}", e),
+        }
+
+        // on a bounded board of a single row the blinker cannot turn and dies out
+        let bounded = board
+            .clone()
+            .with_topology(Topology::Bounded { edge: false });
+        match life.evolve(&bounded, 10) {
+            Evolution::FixedPoint { generation, grid } => {
+                assert_eq!(generation, 2);
+                assert_eq!(grid.count().get(&true), None);
+            }
+            e => panic!("Unexpected evolution {:?}", e),
+        }
+
+        // a glider on a torus returns to where it started
+        let mut torus = DenseBoard::with_bounds(Rect::new((0, 0).into(), (4, 4).into()))
+            .with_topology(Topology::Toroidal);
+        for pos in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)].iter() {
+            torus.set(&(*pos).into(), true);
+        }
+        match life.evolve(&torus, 100) {
+            Evolution::Cycle { start, period, .. } => assert_eq!((start, period), (0, 20)),
+            e => panic!("Unexpected evolution {:?}", e),
+        }
+
+        // pinned cells keep their state
+        let mut board = DenseBoard::with_bounds(Rect::new((0, 0).into(), (2, 2).into()))
+            .with_topology(Topology::Bounded { edge: false });
+        board.set(&(1, 1).into(), true);
+        let pinned = Automaton::new(Rule::conway()).pin((0, 0).into(), true);
+        let after = pinned.run(&board, 5);
+        assert!(after.get(&(0, 0).into()));
+        assert_eq!(after.count().get(&true), Some(&1));
+    }
+}
@@ -0,0 +1,138 @@
+use super::{Grid, Position};
+use crate::{state_err, Error, Result};
+
+use std::io::Write;
+
+/// A colour as red, green and blue intensities.
+pub type Rgb = [u8; 3];
+
+/// Maps tiles to the colours they are drawn with in images.
+///
+/// Any function from a tile to a colour is a palette.
+pub trait Palette<T> {
+    fn rgb(&self, tile: &T) -> Rgb;
+
+    /// Get the brightness of a tile in greyscale images, by default the luma of its colour.
+    fn gray(&self, tile: &T) -> u8 {
+        let [r, g, b] = self.rgb(tile);
+        ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+    }
+}
+
+impl<T, F: Fn(&T) -> Rgb> Palette<T> for F {
+    fn rgb(&self, tile: &T) -> Rgb {
+        self(tile)
+    }
+}
+
+/// Write the pixels of a board over its bounds, each tile becoming a square of `scale` pixels.
+fn write_pixels<G: Grid, W: Write, F: Fn(&G::Tile) -> Vec<u8>>(
+    grid: &G,
+    magic: &str,
+    scale: usize,
+    writer: &mut W,
+    pixel: F,
+) -> Result<()> {
+    if scale == 0 {
+        return Err(state_err!("Cannot scale an image by 0"));
+    }
+
+    let bounds = grid.bounds();
+    let (width, height) = bounds
+        .map(|b| (b.width() as usize, b.height() as usize))
+        .unwrap_or((0, 0));
+
+    write!(
+        writer,
+        "{}\n{} {}\n255\n",
+        magic,
+        width * scale,
+        height * scale
+    )?;
+
+    if let Some(bounds) = bounds {
+        for i in bounds.min.i..=bounds.max.i {
+            let mut row = Vec::new();
+            for j in bounds.min.j..=bounds.max.j {
+                let p = pixel(&grid.get(&Position { i, j }));
+                for _ in 0..scale {
+                    row.extend_from_slice(&p);
+                }
+            }
+
+            for _ in 0..scale {
+                writer.write_all(&row)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a board as a binary colour image in the PPM format.
+pub fn write_ppm<G: Grid, P: Palette<G::Tile>, W: Write>(
+    grid: &G,
+    palette: &P,
+    scale: usize,
+    writer: &mut W,
+) -> Result<()> {
+    write_pixels(grid, "P6", scale, writer, |t| palette.rgb(t).to_vec())
+}
+
+/// Write a board as a binary greyscale image in the PGM format.
+pub fn write_pgm<G: Grid, P: Palette<G::Tile>, W: Write>(
+    grid: &G,
+    palette: &P,
+    scale: usize,
+    writer: &mut W,
+) -> Result<()> {
+    write_pixels(grid, "P5", scale, writer, |t| vec![palette.gray(t)])
+}
+
+/// Save a board as an image to a file, identified by a path. Paths ending in `.pgm` are saved in
+/// greyscale, all others in colour.
+pub fn save<G: Grid, P: Palette<G::Tile>>(
+    grid: &G,
+    palette: &P,
+    scale: usize,
+    path: &str,
+) -> Result<()> {
+    let f = std::fs::File::create(path).map_err(|e| Error::from(e).in_file(path))?;
+    let mut writer = std::io::BufWriter::new(f);
+
+    if path.ends_with(".pgm") {
+        write_pgm(grid, palette, scale, &mut writer)?;
+    } else {
+        write_ppm(grid, palette, scale, &mut writer)?;
+    }
+
+    writer.flush().map_err(|e| Error::from(e).in_file(path))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::Board;
+
+    #[test]
+    fn test_write_images() -> Result<()> {
+        let mut board = Board::new();
+        board.set(&(5, 3).into(), true);
+        board.set(&(5, 4).into(), false);
+
+        let palette = |t: &bool| if *t { [255, 255, 0] } else { [0, 0, 0] };
+
+        let mut ppm = Vec::new();
+        write_ppm(&board, &palette, 1, &mut ppm)?;
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\xff\x00\x00\x00\x00");
+
+        let mut pgm = Vec::new();
+        write_pgm(&board, &palette, 2, &mut pgm)?;
+        assert_eq!(pgm, b"P5\n4 2\n255\n\xe1\xe1\x00\x00\xe1\xe1\x00\x00");
+
+        assert!(write_pgm(&board, &palette, 0, &mut pgm).is_err());
+
+        Ok(())
+    }
+}
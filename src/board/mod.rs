@@ -0,0 +1,1258 @@
+use crate::{parse_err, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub mod animation;
+pub mod automaton;
+pub mod image;
+pub mod nd;
+pub mod path;
+pub mod snapshot;
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+pub struct Position {
+    pub i: i64,
+    pub j: i64,
+}
+
+impl Position {
+    pub const ZERO: Position = Position { i: 0, j: 0 };
+}
+
+impl Position {
+    /// The number of steps between two positions when moving only north, south, east or west.
+    pub fn manhattan(&self, other: &Position) -> i64 {
+        (self.i - other.i).abs() + (self.j - other.j).abs()
+    }
+
+    /// The number of steps between two positions when also moving diagonally.
+    pub fn chebyshev(&self, other: &Position) -> i64 {
+        (self.i - other.i).abs().max((self.j - other.j).abs())
+    }
+
+    /// Rotate by 90 degrees counter-clockwise about the origin, like `Direction::turn_left`.
+    pub fn rotate_left(&self) -> Position {
+        Position {
+            i: -self.j,
+            j: self.i,
+        }
+    }
+
+    /// Rotate by 90 degrees clockwise about the origin, like `Direction::turn_right`.
+    pub fn rotate_right(&self) -> Position {
+        Position {
+            i: self.j,
+            j: -self.i,
+        }
+    }
+
+    /// Iterate over the four positions sharing an edge with this one, clockwise from north.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Position> {
+        let pos = *self;
+        Direction::ALL.iter().map(move |d| pos + d.to_ofs().into())
+    }
+
+    /// Iterate over the eight positions sharing an edge or a corner with this one, clockwise from
+    /// north.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Position> {
+        let pos = *self;
+        Direction::ALL8.iter().map(move |d| pos + d.to_ofs().into())
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f, "({}, {})", self.j, self.i)
+    }
+}
+
+impl std::ops::Add for Position {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Position {
+            i: self.i + rhs.i,
+            j: self.j + rhs.j,
+        }
+    }
+}
+
+impl std::ops::AddAssign for Position {
+    fn add_assign(&mut self, rhs: Self) {
+        self.i += rhs.i;
+        self.j += rhs.j;
+    }
+}
+
+impl std::ops::Sub for Position {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Position {
+            i: self.i - rhs.i,
+            j: self.j - rhs.j,
+        }
+    }
+}
+
+impl std::ops::SubAssign for Position {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.i -= rhs.i;
+        self.j -= rhs.j;
+    }
+}
+
+impl std::ops::Neg for Position {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Position {
+            i: -self.i,
+            j: -self.j,
+        }
+    }
+}
+
+impl std::ops::Mul<i64> for Position {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self {
+        Position {
+            i: self.i * rhs,
+            j: self.j * rhs,
+        }
+    }
+}
+
+impl From<(i64, i64)> for Position {
+    fn from(p: (i64, i64)) -> Self {
+        Position { i: p.0, j: p.1 }
+    }
+}
+
+impl From<Direction> for Position {
+    fn from(d: Direction) -> Self {
+        d.to_ofs().into()
+    }
+}
+
+/// A rectangular region of positions, including both corners.
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[serde(from = "Corners")]
+pub struct Rect {
+    pub min: Position,
+    pub max: Position,
+}
+
+/// The serialized form of a `Rect`, whose corners may come in any order.
+#[derive(Deserialize)]
+struct Corners {
+    min: Position,
+    max: Position,
+}
+
+impl From<Corners> for Rect {
+    fn from(c: Corners) -> Self {
+        Rect::new(c.min, c.max)
+    }
+}
+
+impl std::fmt::Display for Rect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f, "{}..={}", self.min, self.max)
+    }
+}
+
+impl Rect {
+    /// Create the region spanned by two opposite corners, given in any order.
+    pub fn new(a: Position, b: Position) -> Rect {
+        Rect {
+            min: Position {
+                i: a.i.min(b.i),
+                j: a.j.min(b.j),
+            },
+            max: Position {
+                i: a.i.max(b.i),
+                j: a.j.max(b.j),
+            },
+        }
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> i64 {
+        self.max.i - self.min.i + 1
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> i64 {
+        self.max.j - self.min.j + 1
+    }
+
+    pub fn area(&self) -> usize {
+        (self.height() * self.width()) as usize
+    }
+
+    pub fn contains(&self, pos: &Position) -> bool {
+        (self.min.i..=self.max.i).contains(&pos.i) && (self.min.j..=self.max.j).contains(&pos.j)
+    }
+
+    /// Get the region covered by both rectangles, or None if they do not overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Position {
+            i: self.min.i.max(other.min.i),
+            j: self.min.j.max(other.min.j),
+        };
+        let max = Position {
+            i: self.max.i.min(other.max.i),
+            j: self.max.j.min(other.max.j),
+        };
+
+        if min.i > max.i || min.j > max.j {
+            return None;
+        }
+
+        Some(Rect { min, max })
+    }
+
+    /// Get the smallest rectangle covering both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::new(
+            Position {
+                i: self.min.i.min(other.min.i),
+                j: self.min.j.min(other.min.j),
+            },
+            Position {
+                i: self.max.i.max(other.max.i),
+                j: self.max.j.max(other.max.j),
+            },
+        )
+    }
+
+    /// Get the four corners, clockwise from the top left.
+    pub fn corners(&self) -> [Position; 4] {
+        [
+            self.min,
+            Position {
+                i: self.min.i,
+                j: self.max.j,
+            },
+            self.max,
+            Position {
+                i: self.max.i,
+                j: self.min.j,
+            },
+        ]
+    }
+
+    /// Get the smallest rectangle covering this one and a position.
+    pub fn including(&self, pos: &Position) -> Rect {
+        self.union(&Rect::from(*pos))
+    }
+
+    /// Map a position into the region, wrapping around at its edges.
+    pub fn wrap(&self, pos: &Position) -> Position {
+        Position {
+            i: self.min.i + (pos.i - self.min.i).rem_euclid(self.height()),
+            j: self.min.j + (pos.j - self.min.j).rem_euclid(self.width()),
+        }
+    }
+
+    /// The Manhattan distance between two positions in the region when steps may wrap around
+    /// its edges.
+    pub fn wrapped_manhattan(&self, a: &Position, b: &Position) -> i64 {
+        let axis = |d: i64, size: i64| {
+            let d = d.rem_euclid(size);
+            d.min(size - d)
+        };
+
+        axis(a.i - b.i, self.height()) + axis(a.j - b.j, self.width())
+    }
+
+    /// Iterate over all positions in the region, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let (min, max) = (self.min, self.max);
+        (min.i..=max.i).flat_map(move |i| (min.j..=max.j).map(move |j| Position { i, j }))
+    }
+}
+
+impl From<Position> for Rect {
+    fn from(pos: Position) -> Self {
+        Rect { min: pos, max: pos }
+    }
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Direction {
+    North,
+    South,
+    West,
+    East,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+}
+
+impl std::fmt::Debug for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let c = match self {
+            Direction::North => "🠉",
+            Direction::South => "🠋",
+            Direction::West => "🠈 ",
+            Direction::East => "🠊 ",
+            Direction::NorthEast => "🠭",
+            Direction::SouthEast => "🠮",
+            Direction::SouthWest => "🠯",
+            Direction::NorthWest => "🠬",
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The four diagonal directions, clockwise from north-east.
+    pub const DIAGONALS: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// All eight cardinal and diagonal directions, clockwise from north.
+    pub const ALL8: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn is_diagonal(&self) -> bool {
+        Direction::DIAGONALS.contains(self)
+    }
+
+    /// Turn by 90 degrees counter-clockwise.
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+            Direction::NorthEast => Direction::NorthWest,
+            Direction::SouthEast => Direction::NorthEast,
+            Direction::SouthWest => Direction::SouthEast,
+            Direction::NorthWest => Direction::SouthWest,
+        }
+    }
+
+    /// Turn by 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+            Direction::NorthEast => Direction::SouthEast,
+            Direction::SouthEast => Direction::SouthWest,
+            Direction::SouthWest => Direction::NorthWest,
+            Direction::NorthWest => Direction::NorthEast,
+        }
+    }
+
+    /// Get the movement command of the direction, which only exists for cardinal directions.
+    pub fn to_input(&self) -> Option<i64> {
+        match self {
+            Direction::North => Some(1),
+            Direction::South => Some(2),
+            Direction::West => Some(3),
+            Direction::East => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Get the direction an arrow (`^`, `>`, `v`, `<`, or their Unicode forms) or a letter
+    /// (`N`/`E`/`S`/`W` or `U`/`R`/`D`/`L`, in either case) stands for.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | '↑' | 'N' | 'n' | 'U' | 'u' => Some(Direction::North),
+            '>' | '→' | 'E' | 'e' | 'R' | 'r' => Some(Direction::East),
+            'v' | '↓' | 'S' | 's' | 'D' | 'd' => Some(Direction::South),
+            '<' | '←' | 'W' | 'w' | 'L' | 'l' => Some(Direction::West),
+            '↗' => Some(Direction::NorthEast),
+            '↘' => Some(Direction::SouthEast),
+            '↙' => Some(Direction::SouthWest),
+            '↖' => Some(Direction::NorthWest),
+            _ => None,
+        }
+    }
+
+    pub fn to_ofs(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+            Direction::East => (0, 1),
+            Direction::NorthEast => (-1, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::SouthWest => (1, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl std::convert::TryFrom<char> for Direction {
+    type Error = Error;
+    fn try_from(c: char) -> Result<Direction> {
+        Direction::from_char(c).ok_or_else(|| parse_err!("Invalid direction '{}'", c))
+    }
+}
+
+/// A tile that is read from and drawn as a single character.
+pub trait Tile: Sized {
+    /// Get the tile a character stands for, or None if it does not stand for any.
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+/// A tile that is either set, drawn as `#`, or clear, drawn as `.`.
+impl Tile for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// A tile that differs between two boards, see `Grid::diff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change<T> {
+    pub pos: Position,
+    pub old: T,
+    pub new: T,
+}
+
+/// Which positions exist beyond the bounds of a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Topology<T> {
+    /// Every position exists, and those without a tile hold the default tile.
+    Infinite,
+
+    /// Only positions within the bounds exist, and looking beyond them finds the edge tile.
+    Bounded { edge: T },
+
+    /// Leaving the bounds on one side re-enters them on the opposite side.
+    Toroidal,
+}
+
+/// Storage for the tiles of a two-dimensional board, shared by the sparse `Board` and the dense
+/// `DenseBoard`. Positions without a stored tile read as the default tile.
+pub trait Grid {
+    type Tile: Default + Copy + PartialEq;
+
+    fn get(&self, pos: &Position) -> Self::Tile;
+
+    fn set(&mut self, pos: &Position, tile: Self::Tile);
+
+    /// Get the smallest region covering all stored tiles, or None if there are none.
+    fn bounds(&self) -> Option<Rect>;
+
+    /// Iterate over all stored tiles and their positions.
+    fn iter(&self) -> Box<dyn Iterator<Item = (Position, Self::Tile)> + '_>;
+
+    fn topology(&self) -> Topology<Self::Tile>;
+
+    fn set_topology(&mut self, topology: Topology<Self::Tile>);
+
+    fn with_topology(mut self, topology: Topology<Self::Tile>) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_topology(topology);
+        self
+    }
+
+    /// Get the position a position refers to under the topology of the board, or None if it
+    /// does not exist.
+    fn resolve(&self, pos: &Position) -> Option<Position> {
+        match (self.topology(), self.bounds()) {
+            (Topology::Infinite, _) => Some(*pos),
+            (Topology::Bounded { .. }, bounds) => bounds.filter(|b| b.contains(pos)).map(|_| *pos),
+            (Topology::Toroidal, Some(bounds)) => Some(bounds.wrap(pos)),
+            (Topology::Toroidal, None) => Some(*pos),
+        }
+    }
+
+    /// Get the tile at a position under the topology of the board, i.e. the edge tile of bounded
+    /// boards outside of their bounds, and the wrapped-around tile of toroidal boards.
+    fn lookup(&self, pos: &Position) -> Self::Tile {
+        match (self.resolve(pos), self.topology()) {
+            (Some(p), _) => self.get(&p),
+            (None, Topology::Bounded { edge }) => edge,
+            (None, _) => Self::Tile::default(),
+        }
+    }
+
+    /// Get the existing positions sharing an edge with a position, see `Position::neighbors4`.
+    fn neighbors4(&self, pos: &Position) -> Vec<Position> {
+        pos.neighbors4().filter_map(|q| self.resolve(&q)).collect()
+    }
+
+    /// Get the existing positions sharing an edge or a corner with a position, see
+    /// `Position::neighbors8`.
+    fn neighbors8(&self, pos: &Position) -> Vec<Position> {
+        pos.neighbors8().filter_map(|q| self.resolve(&q)).collect()
+    }
+
+    fn where_is(&self, tile: &Self::Tile) -> Option<Position> {
+        self.iter().find(|(_, t)| t == tile).map(|(pos, _)| pos)
+    }
+
+    fn where_are(&self, tile: &Self::Tile) -> Vec<Position> {
+        self.iter()
+            .filter(|(_, t)| t == tile)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Set every tile in a region to the same tile.
+    fn fill(&mut self, rect: &Rect, tile: Self::Tile) {
+        self.map_region(rect, |_| tile);
+    }
+
+    /// Replace every tile in a region by a function of it.
+    fn map_region<F: Fn(Self::Tile) -> Self::Tile>(&mut self, rect: &Rect, f: F) {
+        for pos in rect.positions() {
+            let t = self.get(&pos);
+            self.set(&pos, f(t));
+        }
+    }
+
+    /// Count the tiles in a region that satisfy a predicate.
+    fn count_region<P: Fn(&Self::Tile) -> bool>(&self, rect: &Rect, predicate: P) -> usize {
+        rect.positions()
+            .filter(|pos| predicate(&self.get(pos)))
+            .count()
+    }
+
+    /// Count the tiles among the eight neighbours of a position that satisfy a predicate,
+    /// looking them up according to the topology of the board.
+    fn count_neighbors<P: Fn(&Self::Tile) -> bool>(&self, pos: &Position, predicate: P) -> usize {
+        pos.neighbors8()
+            .filter(|q| predicate(&self.lookup(q)))
+            .count()
+    }
+
+    /// Parse a board from a text with one character per tile, where line `i` and character `j`
+    /// of it become the tile at `Position { i, j }`.
+    ///
+    /// Unknown characters are reported with the 1-based line and column they occur at.
+    fn parse(s: &str) -> Result<Self>
+    where
+        Self: Default,
+        Self::Tile: Tile,
+    {
+        let mut board = Self::default();
+
+        for (i, line) in s.lines().enumerate() {
+            for (j, c) in line.chars().enumerate() {
+                let t = Self::Tile::from_char(c).ok_or_else(|| {
+                    parse_err!("Unknown tile '{}'", c)
+                        .at_column(j + 1)
+                        .on_line(i + 1, line)
+                })?;
+
+                board.set(
+                    &Position {
+                        i: i as i64,
+                        j: j as i64,
+                    },
+                    t,
+                );
+            }
+        }
+
+        Ok(board)
+    }
+
+    /// Draw the board over its extent with one character per tile, the inverse of `parse`.
+    fn render(&self) -> String
+    where
+        Self::Tile: Tile,
+    {
+        let mut out = String::new();
+        if let Some(bounds) = self.bounds() {
+            for i in bounds.min.i..=bounds.max.i {
+                for j in bounds.min.j..=bounds.max.j {
+                    out.push(self.get(&Position { i, j }).to_char());
+                }
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+
+    /// Get the tiles that differ in another board over the extent of both, in row-major order.
+    fn diff(&self, other: &Self) -> Vec<Change<Self::Tile>> {
+        let region = match (self.bounds(), other.bounds()) {
+            (Some(a), Some(b)) => a.union(&b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => return Vec::new(),
+        };
+
+        region
+            .positions()
+            .filter_map(|pos| {
+                let (old, new) = (self.get(&pos), other.get(&pos));
+                if old != new {
+                    Some(Change { pos, old, new })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn count(&self) -> HashMap<Self::Tile, usize>
+    where
+        Self::Tile: Eq + std::hash::Hash,
+    {
+        let mut out = HashMap::new();
+
+        for (_, t) in self.iter() {
+            out.entry(t).and_modify(|c| *c += 1).or_insert(1);
+        }
+
+        out
+    }
+}
+
+/// Draw a grid row by row over its extent.
+fn fmt_grid<G: Grid>(
+    grid: &G,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::result::Result<(), std::fmt::Error>
+where
+    G::Tile: std::fmt::Display,
+{
+    if let Some(bounds) = grid.bounds() {
+        for i in bounds.min.i..=bounds.max.i {
+            for j in bounds.min.j..=bounds.max.j {
+                write!(f, "{}", grid.get(&Position { i, j }))?;
+            }
+            writeln!(f)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A board storing only the tiles that were set, suitable for boards that are sparse or grow in
+/// unknown directions.
+#[derive(Debug, Clone)]
+pub struct Board<T> {
+    tiles: HashMap<Position, T>,
+    bounds: Option<Rect>,
+    topology: Topology<T>,
+}
+
+impl<T: std::fmt::Display + std::default::Default + std::marker::Copy + std::cmp::PartialEq>
+    std::fmt::Display for Board<T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        fmt_grid(self, f)
+    }
+}
+
+impl<T> Default for Board<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Board<T> {
+    pub fn new() -> Self {
+        Board {
+            tiles: HashMap::new(),
+            bounds: None,
+            topology: Topology::Infinite,
+        }
+    }
+}
+
+impl<T: std::default::Default + std::marker::Copy + std::cmp::PartialEq> Grid for Board<T> {
+    type Tile = T;
+
+    fn get(&self, pos: &Position) -> T {
+        self.tiles.get(pos).copied().unwrap_or_default()
+    }
+
+    fn set(&mut self, pos: &Position, tile: T) {
+        self.tiles.insert(*pos, tile);
+
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(pos),
+            None => Rect::from(*pos),
+        });
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    fn topology(&self) -> Topology<T> {
+        self.topology
+    }
+
+    fn set_topology(&mut self, topology: Topology<T>) {
+        self.topology = topology;
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Position, T)> + '_> {
+        Box::new(self.tiles.iter().map(|(pos, t)| (*pos, *t)))
+    }
+}
+
+/// The serialized form of a `Board`, listing its tiles by position since formats like JSON only
+/// allow strings as map keys.
+#[derive(Serialize, Deserialize)]
+struct BoardData<T> {
+    topology: Topology<T>,
+    tiles: Vec<(Position, T)>,
+}
+
+impl<T: Serialize + Copy> Serialize for Board<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let mut tiles: Vec<(Position, T)> = self.tiles.iter().map(|(p, t)| (*p, *t)).collect();
+        tiles.sort_by_key(|(pos, _)| *pos);
+
+        BoardData {
+            topology: self.topology,
+            tiles,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Board<T>
+where
+    T: Deserialize<'de> + std::default::Default + std::marker::Copy + std::cmp::PartialEq,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let data = BoardData::deserialize(deserializer)?;
+
+        let mut board = Board::new().with_topology(data.topology);
+        for (pos, tile) in data.tiles {
+            board.set(&pos, tile);
+        }
+
+        Ok(board)
+    }
+}
+
+/// A board storing every tile of a rectangular region in a `Vec`, suitable for boards that are
+/// filled densely. Setting a tile outside of the region grows it.
+///
+/// It serializes as its bounds, its tiles in row-major order and its topology.
+#[derive(Debug, Clone)]
+pub struct DenseBoard<T> {
+    bounds: Option<Rect>,
+    /// The region `tiles` holds, which grows ahead of the bounds.
+    capacity: Option<Rect>,
+    tiles: Vec<T>,
+    topology: Topology<T>,
+}
+
+/// Boards are equal if they have the same tiles within the same bounds, however much room they
+/// have reserved to grow.
+impl<T: std::default::Default + std::marker::Copy + std::cmp::PartialEq> PartialEq
+    for DenseBoard<T>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bounds == other.bounds
+            && self.topology == other.topology
+            && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: std::default::Default + std::marker::Copy + std::cmp::Eq> Eq for DenseBoard<T> {}
+
+impl<T: std::fmt::Display + std::default::Default + std::marker::Copy + std::cmp::PartialEq>
+    std::fmt::Display for DenseBoard<T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        fmt_grid(self, f)
+    }
+}
+
+impl<T: std::default::Default + std::marker::Copy> Default for DenseBoard<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: std::default::Default + std::marker::Copy> DenseBoard<T> {
+    pub fn new() -> Self {
+        DenseBoard {
+            bounds: None,
+            capacity: None,
+            tiles: Vec::new(),
+            topology: Topology::Infinite,
+        }
+    }
+
+    /// Create a board covering a region, filled with the default tile.
+    pub fn with_bounds(bounds: Rect) -> Self {
+        DenseBoard {
+            bounds: Some(bounds),
+            capacity: Some(bounds),
+            tiles: vec![T::default(); bounds.area()],
+            topology: Topology::Infinite,
+        }
+    }
+
+    fn index(&self, pos: &Position) -> Option<usize> {
+        self.bounds.filter(|b| b.contains(pos))?;
+        let capacity = self.capacity?;
+
+        let i = (pos.i - capacity.min.i) as usize;
+        let j = (pos.j - capacity.min.j) as usize;
+
+        Some(i * capacity.width() as usize + j)
+    }
+
+    /// Grow the bounds so that they include another region.
+    ///
+    /// When the tiles need more room, they get at least half as many rows or columns again as they
+    /// had in every direction they grow in, so that growing a board one tile at a time takes
+    /// amortized constant time per tile.
+    fn grow_to(&mut self, rect: &Rect) {
+        let (bounds, capacity) = match (self.bounds, self.capacity) {
+            (Some(bounds), Some(capacity)) => (bounds, capacity),
+            _ => {
+                self.tiles = vec![T::default(); rect.area()];
+                self.bounds = Some(*rect);
+                self.capacity = Some(*rect);
+                return;
+            }
+        };
+
+        self.bounds = Some(bounds.union(rect));
+        if capacity.intersection(rect) == Some(*rect) {
+            return;
+        }
+
+        let (height, width) = (capacity.height(), capacity.width());
+        let mut grown = capacity;
+        if rect.min.i < capacity.min.i {
+            grown.min.i = rect.min.i.min(capacity.min.i - height / 2);
+        }
+        if rect.max.i > capacity.max.i {
+            grown.max.i = rect.max.i.max(capacity.max.i + height / 2);
+        }
+        if rect.min.j < capacity.min.j {
+            grown.min.j = rect.min.j.min(capacity.min.j - width / 2);
+        }
+        if rect.max.j > capacity.max.j {
+            grown.max.j = rect.max.j.max(capacity.max.j + width / 2);
+        }
+
+        let mut tiles = vec![T::default(); grown.area()];
+        let (width, grown_width) = (width as usize, grown.width() as usize);
+        let offset = (capacity.min.j - grown.min.j) as usize;
+        for row in 0..height as usize {
+            let from = row * width;
+            let to = (row + (capacity.min.i - grown.min.i) as usize) * grown_width + offset;
+            tiles[to..to + width].copy_from_slice(&self.tiles[from..from + width]);
+        }
+
+        self.tiles = tiles;
+        self.capacity = Some(grown);
+    }
+}
+
+/// The serialized form of a `DenseBoard`, with only the tiles within its bounds, checked against
+/// the bounds before use.
+#[derive(Serialize, Deserialize)]
+struct DenseBoardData<T> {
+    bounds: Option<Rect>,
+    tiles: Vec<T>,
+    topology: Topology<T>,
+}
+
+impl<T> Serialize for DenseBoard<T>
+where
+    T: Serialize + std::default::Default + std::marker::Copy + std::cmp::PartialEq,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        DenseBoardData {
+            bounds: self.bounds,
+            tiles: self.iter().map(|(_, t)| t).collect(),
+            topology: self.topology,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DenseBoard<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let data = DenseBoardData::deserialize(deserializer)?;
+
+        let area = data.bounds.map(|b| b.area()).unwrap_or(0);
+        if data.tiles.len() != area {
+            return Err(serde::de::Error::custom(format!(
+                "expected {} tiles for the bounds, got {}",
+                area,
+                data.tiles.len()
+            )));
+        }
+
+        Ok(DenseBoard {
+            bounds: data.bounds,
+            capacity: data.bounds,
+            tiles: data.tiles,
+            topology: data.topology,
+        })
+    }
+}
+
+impl<T: std::default::Default + std::marker::Copy + std::cmp::PartialEq> Grid for DenseBoard<T> {
+    type Tile = T;
+
+    fn get(&self, pos: &Position) -> T {
+        self.index(pos)
+            .map(|idx| self.tiles[idx])
+            .unwrap_or_default()
+    }
+
+    fn set(&mut self, pos: &Position, tile: T) {
+        self.grow_to(&Rect::from(*pos));
+
+        if let Some(idx) = self.index(pos) {
+            self.tiles[idx] = tile;
+        }
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    fn topology(&self) -> Topology<T> {
+        self.topology
+    }
+
+    fn set_topology(&mut self, topology: Topology<T>) {
+        self.topology = topology;
+    }
+
+    fn map_region<F: Fn(T) -> T>(&mut self, rect: &Rect, f: F) {
+        self.grow_to(rect);
+
+        // rows of the region are contiguous in storage
+        let width = rect.width() as usize;
+        for i in rect.min.i..=rect.max.i {
+            if let Some(start) = self.index(&Position { i, j: rect.min.j }) {
+                for t in self.tiles[start..start + width].iter_mut() {
+                    *t = f(*t);
+                }
+            }
+        }
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Position, T)> + '_> {
+        let positions = self.bounds.into_iter().flat_map(|b| b.positions());
+        Box::new(positions.map(move |pos| (pos, self.get(&pos))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_dense_board() {
+        let mut board = DenseBoard::new();
+        board.set(&Position { i: 1, j: 2 }, 'a');
+        board.set(&Position { i: -1, j: 0 }, 'b');
+        board.set(&Position { i: 0, j: 1 }, 'c');
+
+        assert_eq!(
+            board.bounds(),
+            Some(Rect::new((-1, 0).into(), (1, 2).into()))
+        );
+        assert_eq!(board.get(&Position { i: 1, j: 2 }), 'a');
+        assert_eq!(board.get(&Position { i: 5, j: 5 }), '\0');
+        assert_eq!(board.where_is(&'c'), Some(Position { i: 0, j: 1 }));
+        assert_eq!(board.count().get(&'\0'), Some(&6));
+
+        let mut sparse = Board::new();
+        for (pos, t) in board.iter() {
+            sparse.set(&pos, t);
+        }
+        assert_eq!(sparse.where_are(&'b'), vec![Position { i: -1, j: 0 }]);
+        assert_eq!(sparse.bounds(), board.bounds());
+
+        let mut sparse = Board::new();
+        assert_eq!(sparse.bounds(), None);
+        assert_eq!(sparse.render(), "");
+
+        sparse.set(&(3, 4).into(), true);
+        sparse.set(&(2, 6).into(), true);
+        assert_eq!(
+            sparse.bounds(),
+            Some(Rect::new((2, 4).into(), (3, 6).into()))
+        );
+        assert_eq!(sparse.render(), "..#\n#..\n");
+    }
+
+    #[test]
+    fn test_dense_board_growth() {
+        // grow up, down, left and right, one tile at a time
+        let mut board = DenseBoard::new();
+        let mut sparse = Board::new();
+        for k in 0..100 {
+            for pos in [(k, 0), (-k, 1), (2, k), (3, -k)] {
+                board.set(&pos.into(), k);
+                sparse.set(&pos.into(), k);
+            }
+        }
+
+        let bounds = Rect::new((-99, -99).into(), (99, 99).into());
+        assert_eq!(board.bounds(), Some(bounds));
+        assert!(bounds.positions().all(|p| board.get(&p) == sparse.get(&p)));
+
+        // the room to grow is at most a few times the bounds
+        assert!(board.tiles.len() <= 4 * bounds.area());
+
+        // and does not show in the bounds, comparisons or serialization
+        let mut fitted = DenseBoard::with_bounds(bounds);
+        for (pos, t) in board.iter() {
+            fitted.set(&pos, t);
+        }
+        assert_eq!(fitted, board);
+        assert_ne!(fitted.tiles.len(), board.tiles.len());
+        assert_eq!(
+            serde_json::to_string(&fitted).unwrap(),
+            serde_json::to_string(&board).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let pos = Position { i: 2, j: 5 };
+
+        let n4: Vec<Position> = pos.neighbors4().collect();
+        assert_eq!(
+            n4,
+            vec![(1, 5).into(), (2, 6).into(), (3, 5).into(), (2, 4).into()]
+        );
+        assert_eq!(pos.neighbors8().count(), 8);
+        assert!(pos.neighbors8().all(|q| q != pos));
+
+        let mut board = Board::new();
+        board.set(&(1, 4).into(), true);
+        board.set(&(2, 6).into(), true);
+        board.set(&(2, 5).into(), true);
+        assert_eq!(board.count_neighbors(&pos, |t| *t), 2);
+
+        let corner = Position { i: 1, j: 4 };
+        assert_eq!(board.neighbors8(&corner).len(), 8);
+
+        let board = board.with_topology(Topology::Bounded { edge: true });
+        assert_eq!(board.neighbors8(&corner).len(), 3);
+        assert!(board.lookup(&(0, 0).into()));
+        assert_eq!(board.count_neighbors(&corner, |t| *t), 6);
+
+        let board = board.with_topology(Topology::Toroidal);
+        assert_eq!(board.resolve(&(0, 3).into()), Some((2, 6).into()));
+        assert_eq!(board.count_neighbors(&corner, |t| *t), 4);
+
+        for d in Direction::ALL8.iter() {
+            assert_eq!(d.turn_left().turn_right(), *d);
+            assert_eq!(d.is_diagonal(), d.to_input().is_none());
+        }
+    }
+
+    #[test]
+    fn test_position_arithmetic() -> Result<()> {
+        let a = Position { i: 2, j: -3 };
+        let b = Position { i: -1, j: 1 };
+
+        assert_eq!(a - b, (3, -4).into());
+        assert_eq!(-a, (-2, 3).into());
+        assert_eq!(b * 3, (-3, 3).into());
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.rotate_left().rotate_right(), a);
+        assert_eq!(a.rotate_right().rotate_right(), -a);
+
+        for d in Direction::ALL8.iter() {
+            assert_eq!(Position::from(*d).rotate_right(), d.turn_right().into());
+            assert_eq!(Position::from(*d).rotate_left(), d.turn_left().into());
+        }
+
+        assert_eq!(Direction::try_from('<')?, Direction::West);
+        assert_eq!(Direction::from_char('U'), Some(Direction::North));
+        assert_eq!(Direction::from_char('x'), None);
+        assert!(Direction::try_from('x').is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut a = Board::new();
+        a.set(&(0, 0).into(), 1);
+        a.set(&(1, 1).into(), 2);
+
+        let mut b = a.clone();
+        b.set(&(1, 1).into(), 3);
+        b.set(&(2, 0).into(), 4);
+
+        let changes = a.diff(&b);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].pos, (1, 1).into());
+        assert_eq!((changes[0].old, changes[0].new), (2, 3));
+        assert_eq!((changes[1].old, changes[1].new), (0, 4));
+
+        assert!(a.diff(&a).is_empty());
+        assert_eq!(Board::<i32>::new().diff(&a).len(), 2);
+    }
+
+    #[test]
+    fn test_rect() {
+        let a = Rect::new((3, 4).into(), (1, 0).into());
+        assert_eq!(a.min, (1, 0).into());
+        assert_eq!(a.area(), 15);
+        assert!(a.contains(&(2, 4).into()));
+        assert!(!a.contains(&(0, 4).into()));
+
+        let b = Rect::new((2, 3).into(), (5, 8).into());
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new((2, 3).into(), (3, 4).into()))
+        );
+        assert_eq!(a.union(&b), Rect::new((1, 0).into(), (5, 8).into()));
+        assert_eq!(a.wrapped_manhattan(&(1, 0).into(), &(3, 4).into()), 2);
+        assert_eq!(
+            a.intersection(&Rect::new((9, 9).into(), (9, 9).into())),
+            None
+        );
+
+        let positions: Vec<Position> = Rect::new((0, 0).into(), (1, 1).into())
+            .positions()
+            .collect();
+        assert_eq!(
+            positions,
+            vec![(0, 0).into(), (0, 1).into(), (1, 0).into(), (1, 1).into()]
+        );
+
+        let mut sparse = Board::new();
+        let mut dense = DenseBoard::new();
+        sparse.fill(&a, 1);
+        dense.fill(&a, 1);
+        sparse.map_region(&b, |t| t + 1);
+        dense.map_region(&b, |t| t + 1);
+
+        assert!(a
+            .union(&b)
+            .positions()
+            .all(|p| sparse.get(&p) == dense.get(&p)));
+        assert_eq!(sparse.count_region(&a, |t| *t == 2), 4);
+        assert_eq!(dense.count_region(&a, |t| *t == 2), 4);
+        assert_eq!(dense.count_region(&b, |t| *t == 1), 20);
+    }
+
+    #[test]
+    fn test_parse_and_render() -> Result<()> {
+        let text = ".#.\n##.\n";
+
+        let board: DenseBoard<bool> = DenseBoard::parse(text)?;
+        assert!(board.get(&Position { i: 1, j: 0 }));
+        assert_eq!(board.render(), text);
+
+        let board: Board<bool> = Board::parse(text)?;
+        assert_eq!(board.render(), text);
+
+        let e = DenseBoard::<bool>::parse(".#.\n#x.\n").unwrap_err();
+        assert_eq!(e.to_string(), "2:2: Unknown tile 'x'\n  | #x.\n  |  ^");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde() -> Result<()> {
+        let mut board = Board::new().with_topology(Topology::Bounded { edge: 9 });
+        board.set(&(2, -1).into(), 5);
+        board.set(&(0, 3).into(), 7);
+
+        let json = serde_json::to_string(&board)?;
+        assert_eq!(
+            json,
+            r#"{"topology":{"Bounded":{"edge":9}},"tiles":[[{"i":0,"j":3},7],[{"i":2,"j":-1},5]]}"#
+        );
+
+        let read: Board<i32> = serde_json::from_str(&json)?;
+        assert_eq!(read.bounds(), board.bounds());
+        assert_eq!(read.topology(), board.topology());
+        assert_eq!(read.to_string(), board.to_string());
+
+        let mut dense = DenseBoard::with_bounds(Rect::new((0, 1).into(), (1, 2).into()))
+            .with_topology(Topology::Toroidal);
+        dense.set(&(1, 1).into(), 4);
+        dense.set(&(0, 2).into(), 3);
+
+        let json = serde_json::to_string(&dense)?;
+        assert_eq!(
+            json,
+            r#"{"bounds":{"min":{"i":0,"j":1},"max":{"i":1,"j":2}},"tiles":[0,3,4,0],"topology":"Toroidal"}"#
+        );
+
+        let read: DenseBoard<i32> = serde_json::from_str(&json)?;
+        assert_eq!(read, dense);
+
+        let short = r#"{"bounds":{"min":{"i":0,"j":0},"max":{"i":1,"j":1}},"tiles":[1],"topology":"Infinite"}"#;
+        let e = serde_json::from_str::<DenseBoard<i32>>(short).unwrap_err();
+        assert!(e
+            .to_string()
+            .starts_with("expected 4 tiles for the bounds, got 1"));
+
+        let rect: Rect = serde_json::from_str(r#"{"min":{"i":2,"j":0},"max":{"i":0,"j":3}}"#)?;
+        assert_eq!(rect, Rect::new((0, 0).into(), (2, 3).into()));
+
+        let directions: Vec<Direction> = serde_json::from_str(r#"["North","SouthWest"]"#)?;
+        assert_eq!(directions, vec![Direction::North, Direction::SouthWest]);
+
+        Ok(())
+    }
+}
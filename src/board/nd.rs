@@ -0,0 +1,331 @@
+use super::Position;
+
+use std::collections::HashMap;
+
+/// A position in `D` dimensions.
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub struct PosN<const D: usize>(pub [i64; D]);
+
+/// A position in three dimensions.
+pub type Pos3 = PosN<3>;
+
+impl<const D: usize> std::fmt::Display for PosN<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let coords: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "({})", coords.join(", "))
+    }
+}
+
+impl<const D: usize> std::ops::Add for PosN<D> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<const D: usize> std::ops::AddAssign for PosN<D> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<const D: usize> std::ops::Sub for PosN<D> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const D: usize> std::ops::SubAssign for PosN<D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const D: usize> std::ops::Neg for PosN<D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self * -1
+    }
+}
+
+impl<const D: usize> std::ops::Mul<i64> for PosN<D> {
+    type Output = Self;
+    fn mul(mut self, rhs: i64) -> Self {
+        for a in self.0.iter_mut() {
+            *a *= rhs;
+        }
+        self
+    }
+}
+
+impl From<Position> for PosN<2> {
+    fn from(p: Position) -> Self {
+        PosN([p.i, p.j])
+    }
+}
+
+impl From<PosN<2>> for Position {
+    fn from(p: PosN<2>) -> Self {
+        Position {
+            i: p.0[0],
+            j: p.0[1],
+        }
+    }
+}
+
+impl<const D: usize> PosN<D> {
+    pub const ZERO: PosN<D> = PosN([0; D]);
+
+    /// The number of steps between two positions when moving along one axis at a time.
+    pub fn manhattan(&self, other: &PosN<D>) -> i64 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum()
+    }
+
+    /// The number of steps between two positions when also moving diagonally.
+    pub fn chebyshev(&self, other: &PosN<D>) -> i64 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a - b).abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Iterate over the `2 * D` positions one step away along a single axis.
+    pub fn neighbors_orthogonal(&self) -> impl Iterator<Item = PosN<D>> {
+        let pos = *self;
+        (0..D).flat_map(move |axis| {
+            [-1, 1].iter().map(move |d| {
+                let mut q = pos;
+                q.0[axis] += d;
+                q
+            })
+        })
+    }
+
+    /// Iterate over the `3^D - 1` positions sharing at least a corner with this one, the
+    /// generalization of `Position::neighbors8`.
+    pub fn neighbors(&self) -> impl Iterator<Item = PosN<D>> {
+        let pos = *self;
+        let around = RectN {
+            min: pos + PosN([-1; D]),
+            max: pos + PosN([1; D]),
+        };
+
+        around.positions().filter(move |q| *q != pos)
+    }
+}
+
+/// A box of positions in `D` dimensions, including both corners.
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+pub struct RectN<const D: usize> {
+    pub min: PosN<D>,
+    pub max: PosN<D>,
+}
+
+impl<const D: usize> From<PosN<D>> for RectN<D> {
+    fn from(pos: PosN<D>) -> Self {
+        RectN { min: pos, max: pos }
+    }
+}
+
+impl<const D: usize> RectN<D> {
+    /// The number of positions in the box.
+    pub fn volume(&self) -> usize {
+        self.min
+            .0
+            .iter()
+            .zip(self.max.0.iter())
+            .map(|(a, b)| (b - a + 1) as usize)
+            .product()
+    }
+
+    pub fn contains(&self, pos: &PosN<D>) -> bool {
+        (0..D).all(|axis| (self.min.0[axis]..=self.max.0[axis]).contains(&pos.0[axis]))
+    }
+
+    /// Get the smallest box covering this one and a position.
+    pub fn including(&self, pos: &PosN<D>) -> RectN<D> {
+        let mut out = *self;
+        for axis in 0..D {
+            out.min.0[axis] = out.min.0[axis].min(pos.0[axis]);
+            out.max.0[axis] = out.max.0[axis].max(pos.0[axis]);
+        }
+        out
+    }
+
+    /// Get the box grown by a number of steps in every direction.
+    pub fn expand(&self, by: i64) -> RectN<D> {
+        RectN {
+            min: self.min - PosN([by; D]),
+            max: self.max + PosN([by; D]),
+        }
+    }
+
+    /// Iterate over all positions in the box, with the last axis changing fastest.
+    pub fn positions(&self) -> impl Iterator<Item = PosN<D>> {
+        let (min, max) = (self.min, self.max);
+        let mut next = if (0..D).all(|axis| min.0[axis] <= max.0[axis]) {
+            Some(min)
+        } else {
+            None
+        };
+
+        std::iter::from_fn(move || {
+            let cur = next?;
+
+            // advance like an odometer
+            let mut pos = cur;
+            next = None;
+            for axis in (0..D).rev() {
+                if pos.0[axis] < max.0[axis] {
+                    pos.0[axis] += 1;
+                    next = Some(pos);
+                    break;
+                }
+                pos.0[axis] = min.0[axis];
+            }
+
+            Some(cur)
+        })
+    }
+}
+
+/// A sparse board in `D` dimensions, the counterpart of `Board` for `PosN` positions.
+///
+/// Positions without a stored tile hold the default tile.
+#[derive(Debug, Clone)]
+pub struct BoardN<T, const D: usize> {
+    tiles: HashMap<PosN<D>, T>,
+    bounds: Option<RectN<D>>,
+}
+
+impl<T, const D: usize> Default for BoardN<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const D: usize> BoardN<T, D> {
+    pub fn new() -> Self {
+        BoardN {
+            tiles: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Get the smallest box covering all stored tiles, or None if there are none.
+    pub fn bounds(&self) -> Option<RectN<D>> {
+        self.bounds
+    }
+}
+
+impl<T: Default + Copy + PartialEq, const D: usize> BoardN<T, D> {
+    pub fn get(&self, pos: &PosN<D>) -> T {
+        self.tiles.get(pos).copied().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, pos: &PosN<D>, tile: T) {
+        self.tiles.insert(*pos, tile);
+
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(pos),
+            None => RectN::from(*pos),
+        });
+    }
+
+    /// Iterate over all stored tiles and their positions.
+    pub fn iter(&self) -> impl Iterator<Item = (PosN<D>, T)> + '_ {
+        self.tiles.iter().map(|(pos, t)| (*pos, *t))
+    }
+
+    pub fn where_are(&self, tile: &T) -> Vec<PosN<D>> {
+        self.iter()
+            .filter(|(_, t)| t == tile)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Count the tiles among the neighbours of a position that satisfy a predicate.
+    pub fn count_neighbors<P: Fn(&T) -> bool>(&self, pos: &PosN<D>, predicate: P) -> usize {
+        pos.neighbors().filter(|q| predicate(&self.get(q))).count()
+    }
+
+    pub fn count(&self) -> HashMap<T, usize>
+    where
+        T: Eq + std::hash::Hash,
+    {
+        let mut out = HashMap::new();
+
+        for t in self.tiles.values() {
+            out.entry(*t).and_modify(|c| *c += 1).or_insert(1);
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::automaton::{Automaton, Rule};
+
+    #[test]
+    fn test_positions() {
+        let a = Pos3::ZERO + PosN([1, -2, 3]);
+        let b = PosN([0, 1, 1]);
+
+        assert_eq!(a - b, PosN([1, -3, 2]));
+        assert_eq!(-b * 2, PosN([0, -2, -2]));
+        assert_eq!(a.manhattan(&b), 6);
+        assert_eq!(a.chebyshev(&b), 3);
+
+        assert_eq!(a.neighbors().count(), 26);
+        assert_eq!(PosN([0; 4]).neighbors().count(), 80);
+        assert_eq!(a.neighbors_orthogonal().count(), 6);
+        assert!(a.neighbors().all(|q| q.chebyshev(&a) == 1));
+
+        let p = Position { i: 3, j: -1 };
+        assert_eq!(Position::from(PosN::from(p)), p);
+        let n2: Vec<Position> = PosN::from(p).neighbors().map(Position::from).collect();
+        let n8: Vec<Position> = p.neighbors8().collect();
+        assert!(n2.len() == 8 && n8.iter().all(|q| n2.contains(q)));
+    }
+
+    /// Run a game of life in `D` dimensions on a starting slice and count the live cells.
+    fn conway<const D: usize>(slice: &[&str], cycles: usize) -> usize {
+        let mut board: BoardN<bool, D> = BoardN::new();
+        for (i, row) in slice.iter().enumerate() {
+            for (j, c) in row.chars().enumerate() {
+                let mut pos = PosN([0; D]);
+                pos.0[0] = i as i64;
+                pos.0[1] = j as i64;
+                board.set(&pos, c == '#');
+            }
+        }
+
+        Automaton::new(Rule::conway())
+            .run(&board, cycles)
+            .where_are(&true)
+            .len()
+    }
+
+    #[test]
+    fn test_board() {
+        let slice = [".#.", "..#", "###"];
+        assert_eq!(conway::<3>(&slice, 6), 112);
+        assert_eq!(conway::<4>(&slice, 6), 848);
+    }
+}
@@ -0,0 +1,252 @@
+use super::{Grid, Position, Rect, Topology};
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/// A shortest path between two positions on a board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    /// The total cost of the path, i.e. the number of steps for unweighted searches.
+    pub distance: u64,
+
+    /// The positions along the path, including the start and the goal.
+    pub positions: Vec<Position>,
+}
+
+/// Follow the predecessors of every visited position back from the goal to the start.
+fn reconstruct(came_from: &HashMap<Position, Position>, goal: Position) -> Vec<Position> {
+    let mut positions = vec![goal];
+
+    let mut cur = goal;
+    while let Some(prev) = came_from.get(&cur) {
+        positions.push(*prev);
+        cur = *prev;
+    }
+
+    positions.reverse();
+    positions
+}
+
+/// Get the region a search between two positions may visit.
+///
+/// On infinite boards this is the bounds together with the start and the goal, plus a margin of
+/// one tile to walk around obstacles on the edge. Other boards are searched within their bounds.
+fn search_region<G: Grid>(grid: &G, start: Position, goal: Position) -> Rect {
+    let bounds = match grid.bounds() {
+        Some(bounds) => bounds.including(&start),
+        None => Rect::from(start),
+    };
+
+    match grid.topology() {
+        Topology::Infinite => {
+            let region = bounds.including(&goal);
+            Rect::new(region.min + (-1, -1).into(), region.max + (1, 1).into())
+        }
+        _ => bounds,
+    }
+}
+
+/// Find a path with the fewest steps between north, south, east and west neighbours, only
+/// entering tiles that are passable.
+///
+/// Neighbours follow the topology of the board. On infinite boards the search stays within one
+/// tile of the stored tiles, the start and the goal, so it terminates.
+pub fn bfs<G: Grid, P: Fn(&G::Tile) -> bool>(
+    grid: &G,
+    start: Position,
+    goal: Position,
+    passable: P,
+) -> Option<Path> {
+    let bounds = search_region(grid, start, goal);
+
+    let mut came_from = HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+
+    while let Some((cur, steps)) = queue.pop_front() {
+        if cur == goal {
+            return Some(Path {
+                distance: steps,
+                positions: reconstruct(&came_from, goal),
+            });
+        }
+
+        for next in grid.neighbors4(&cur) {
+            if next == start
+                || came_from.contains_key(&next)
+                || !bounds.contains(&next)
+                || !passable(&grid.get(&next))
+            {
+                continue;
+            }
+
+            came_from.insert(next, cur);
+            queue.push_back((next, steps + 1));
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path between north, south, east and west neighbours, where `cost` gives the
+/// cost of entering a tile, or None if the tile cannot be entered.
+///
+/// Like `bfs`, the search on infinite boards stays close to the stored tiles.
+pub fn dijkstra<G: Grid, C: Fn(&G::Tile) -> Option<u64>>(
+    grid: &G,
+    start: Position,
+    goal: Position,
+    cost: C,
+) -> Option<Path> {
+    best_first(grid, start, goal, cost, |_| 0)
+}
+
+/// Find the cheapest path like `dijkstra`, guided towards the goal by the Manhattan distance, which
+/// wraps around the edges of toroidal boards.
+///
+/// The result is only guaranteed to be cheapest if entering any tile costs at least 1.
+pub fn astar<G: Grid, C: Fn(&G::Tile) -> Option<u64>>(
+    grid: &G,
+    start: Position,
+    goal: Position,
+    cost: C,
+) -> Option<Path> {
+    let wrap = match grid.topology() {
+        Topology::Toroidal => grid.bounds(),
+        _ => None,
+    };
+
+    best_first(grid, start, goal, cost, |pos| match wrap {
+        Some(bounds) => bounds.wrapped_manhattan(pos, &goal) as u64,
+        None => pos.manhattan(&goal) as u64,
+    })
+}
+
+/// Expand positions in order of their cost so far plus the estimated remaining cost.
+fn best_first<G: Grid, C: Fn(&G::Tile) -> Option<u64>, H: Fn(&Position) -> u64>(
+    grid: &G,
+    start: Position,
+    goal: Position,
+    cost: C,
+    heuristic: H,
+) -> Option<Path> {
+    let bounds = search_region(grid, start, goal);
+
+    let mut came_from = HashMap::new();
+    let mut best = HashMap::new();
+    best.insert(start, 0);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), 0, start)));
+
+    while let Some(Reverse((_, dist, cur))) = queue.pop() {
+        if cur == goal {
+            return Some(Path {
+                distance: dist,
+                positions: reconstruct(&came_from, goal),
+            });
+        }
+
+        if best.get(&cur).map(|d| *d < dist).unwrap_or(false) {
+            // a cheaper way to this position was found after it was queued
+            continue;
+        }
+
+        for next in grid.neighbors4(&cur) {
+            if !bounds.contains(&next) {
+                continue;
+            }
+
+            let next_dist = match cost(&grid.get(&next)) {
+                Some(c) => dist + c,
+                None => continue,
+            };
+
+            if best.get(&next).map(|d| *d <= next_dist).unwrap_or(false) {
+                continue;
+            }
+
+            best.insert(next, next_dist);
+            came_from.insert(next, cur);
+            queue.push(Reverse((next_dist + heuristic(&next), next_dist, next)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::{Board, DenseBoard};
+
+    #[test]
+    fn test_paths() {
+        let mut board = DenseBoard::new().with_topology(Topology::Bounded { edge: '#' });
+        let rows = ["S#...", ".#.#.", "...#G", "....."];
+        for (i, row) in rows.iter().enumerate() {
+            for (j, c) in row.chars().enumerate() {
+                board.set(&(i as i64, j as i64).into(), c);
+            }
+        }
+
+        let start = Position { i: 0, j: 0 };
+        let goal = Position { i: 2, j: 4 };
+        let passable = |c: &char| *c != '#';
+
+        let path = bfs(&board, start, goal, passable).unwrap();
+        assert_eq!(path.distance, 8);
+        assert_eq!(path.positions.len(), 9);
+        assert_eq!(path.positions[0], start);
+        assert_eq!(path.positions[8], goal);
+        for w in path.positions.windows(2) {
+            assert!(w[0].neighbors4().any(|p| p == w[1]));
+        }
+
+        // make the bottom route expensive, so the top route is cheaper
+        board.set(&(3, 3).into(), '~');
+        let cost = |c: &char| match c {
+            '#' => None,
+            '~' => Some(10),
+            _ => Some(1),
+        };
+        assert_eq!(bfs(&board, start, goal, passable).unwrap().distance, 8);
+
+        let path = dijkstra(&board, start, goal, cost).unwrap();
+        assert_eq!(path.distance, 10);
+        assert!(path.positions.contains(&(0, 4).into()));
+        assert_eq!(astar(&board, start, goal, cost), Some(path));
+
+        board.set(&(1, 4).into(), '#');
+        board.set(&(3, 3).into(), '#');
+        assert_eq!(bfs(&board, start, goal, passable), None);
+        assert_eq!(dijkstra(&board, start, goal, cost), None);
+        assert_eq!(astar(&board, start, goal, cost), None);
+
+        // on a torus the goal is one step away across the edge
+        let torus = DenseBoard::with_bounds(Rect::new((0, 0).into(), (4, 4).into()))
+            .with_topology(Topology::Toroidal);
+        let (start, goal) = (Position { i: 2, j: 0 }, Position { i: 2, j: 4 });
+        let path = astar(&torus, start, goal, |_: &char| Some(1)).unwrap();
+        assert_eq!(path.distance, 1);
+        assert_eq!(dijkstra(&torus, start, goal, |_| Some(1)), Some(path));
+
+        // on a sparse board the start and the goal may lie outside the stored tiles
+        let mut sparse = Board::new();
+        sparse.set(&(2, 2).into(), '#');
+        let (start, goal) = (Position { i: 0, j: 0 }, Position { i: 0, j: 3 });
+        let passable = |c: &char| *c != '#';
+        assert_eq!(bfs(&sparse, start, goal, passable).unwrap().distance, 3);
+        assert_eq!(dijkstra(&sparse, start, goal, cost).unwrap().distance, 3);
+        assert_eq!(astar(&sparse, start, goal, cost).unwrap().distance, 3);
+
+        // walls on the edge of the stored tiles can be walked around
+        for j in 0..3 {
+            sparse.set(&(1, j).into(), '#');
+        }
+        let goal = Position { i: 2, j: 0 };
+        assert_eq!(bfs(&sparse, start, goal, passable).unwrap().distance, 4);
+        assert_eq!(astar(&sparse, start, goal, cost).unwrap().distance, 4);
+    }
+}
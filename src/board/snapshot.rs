@@ -0,0 +1,300 @@
+use super::{Grid, Position, Tile};
+use crate::pattern::Pattern;
+use crate::{parse_err, state_err, Error, Result};
+
+use lazy_static::lazy_static;
+use std::io::Write;
+
+lazy_static! {
+    static ref HEADER: Pattern = Pattern::new("snapshot {height}x{width} at {i},{j}").unwrap();
+}
+
+const EMPTY: &str = "snapshot empty";
+
+/// Encode a row of tiles as runs of `<count><tile>`, leaving out counts of 1.
+fn encode_row(tiles: &[char]) -> String {
+    let mut out = String::new();
+
+    let mut i = 0;
+    while i < tiles.len() {
+        let run = tiles[i..].iter().take_while(|c| **c == tiles[i]).count();
+        if run > 1 {
+            out.push_str(&run.to_string());
+        }
+        out.push(tiles[i]);
+        i += run;
+    }
+
+    out
+}
+
+/// Write a board as a run-length encoded text snapshot, which stays small for large boards of
+/// mostly the same tile.
+///
+/// The first line gives the size and the top left position of the bounds. Each following line
+/// holds a row as runs like `12#` for twelve `#` tiles, optionally followed by `*n` if the row
+/// repeats `n` times. Tiles drawn as digits or whitespace cannot be stored. The topology of the
+/// board is not part of the snapshot.
+pub fn write_snapshot<G: Grid, W: Write>(grid: &G, writer: &mut W) -> Result<()>
+where
+    G::Tile: Tile,
+{
+    let bounds = match grid.bounds() {
+        Some(bounds) => bounds,
+        None => {
+            writeln!(writer, "{}", EMPTY)?;
+            return Ok(());
+        }
+    };
+
+    writeln!(
+        writer,
+        "snapshot {}x{} at {},{}",
+        bounds.height(),
+        bounds.width(),
+        bounds.min.i,
+        bounds.min.j
+    )?;
+
+    let mut rows: Vec<(String, usize)> = Vec::new();
+    for i in bounds.min.i..=bounds.max.i {
+        let mut tiles = Vec::new();
+        for j in bounds.min.j..=bounds.max.j {
+            let c = grid.get(&Position { i, j }).to_char();
+            if c.is_ascii_digit() || c.is_whitespace() {
+                return Err(state_err!("Tile '{}' cannot be stored in a snapshot", c));
+            }
+            tiles.push(c);
+        }
+
+        let row = encode_row(&tiles);
+        match rows.last_mut() {
+            Some((last, repeats)) if *last == row => *repeats += 1,
+            _ => rows.push((row, 1)),
+        }
+    }
+
+    for (row, repeats) in rows {
+        if repeats > 1 {
+            writeln!(writer, "{} *{}", row, repeats)?;
+        } else {
+            writeln!(writer, "{}", row)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Decode a row of runs, reporting errors at the column they occur at.
+fn decode_row<T: Tile + Copy>(runs: &str) -> Result<Vec<T>> {
+    let mut out = Vec::new();
+    let mut count = String::new();
+
+    for (col, c) in runs.chars().enumerate() {
+        if c.is_ascii_digit() {
+            count.push(c);
+            continue;
+        }
+
+        let t =
+            T::from_char(c).ok_or_else(|| parse_err!("Unknown tile '{}'", c).at_column(col + 1))?;
+        let n = if count.is_empty() {
+            1
+        } else {
+            std::mem::take(&mut count).parse()?
+        };
+
+        if n == 0 {
+            return Err(parse_err!("Empty run of '{}'", c).at_column(col + 1));
+        }
+
+        for _ in 0..n {
+            out.push(t);
+        }
+    }
+
+    if !count.is_empty() {
+        return Err(parse_err!("Expected a tile after count {}", count));
+    }
+
+    Ok(out)
+}
+
+/// Read a board from a text snapshot written by `write_snapshot`.
+pub fn read_snapshot<G: Grid + Default>(text: &str) -> Result<G>
+where
+    G::Tile: Tile,
+{
+    let mut lines = text.lines().enumerate();
+    let mut grid = G::default();
+
+    let header = match lines.next() {
+        Some((_, line)) if line.trim() == EMPTY => return Ok(grid),
+        Some((_, line)) => line,
+        None => return Err(parse_err!("Empty snapshot")),
+    };
+
+    let captures = HEADER.captures(header).map_err(|e| e.on_line(1, header))?;
+    let parse =
+        |name: &str| -> Result<i64> { captures.parse(name).map_err(|e| e.on_line(1, header)) };
+    let (height, width) = (parse("height")?, parse("width")?);
+    let mut pos = Position {
+        i: parse("i")?,
+        j: parse("j")?,
+    };
+
+    let mut rows = 0;
+    for (n, line) in lines {
+        let (runs, repeats) = match line.split_once(' ') {
+            Some((runs, repeats)) => {
+                let repeats = repeats.trim();
+                let count = repeats.strip_prefix('*').ok_or_else(|| {
+                    parse_err!("Expected '*' before the repeat count").on_line(n + 1, line)
+                })?;
+                (
+                    runs,
+                    count
+                        .parse()
+                        .map_err(|e| Error::from(e).on_line(n + 1, line))?,
+                )
+            }
+            None => (line, 1),
+        };
+
+        if repeats < 1 {
+            return Err(parse_err!("Repeat count must be at least 1").on_line(n + 1, line));
+        }
+        if rows + repeats > height {
+            return Err(parse_err!("Expected {} rows, found more", height).on_line(n + 1, line));
+        }
+
+        let tiles: Vec<G::Tile> = decode_row(runs).map_err(|e| e.on_line(n + 1, line))?;
+        if tiles.len() != width as usize {
+            return Err(
+                parse_err!("Expected {} tiles, found {}", width, tiles.len()).on_line(n + 1, line),
+            );
+        }
+
+        for _ in 0..repeats {
+            for (j, t) in tiles.iter().enumerate() {
+                grid.set(
+                    &Position {
+                        i: pos.i,
+                        j: pos.j + j as i64,
+                    },
+                    *t,
+                );
+            }
+            pos.i += 1;
+            rows += 1;
+        }
+    }
+
+    if rows != height {
+        return Err(parse_err!("Expected {} rows, found {}", height, rows));
+    }
+
+    Ok(grid)
+}
+
+/// Save a board as a text snapshot to a file, identified by a path.
+pub fn save<G: Grid>(grid: &G, path: &str) -> Result<()>
+where
+    G::Tile: Tile,
+{
+    let f = std::fs::File::create(path).map_err(|e| Error::from(e).in_file(path))?;
+    let mut writer = std::io::BufWriter::new(f);
+
+    write_snapshot(grid, &mut writer).map_err(|e| e.in_file(path))?;
+
+    writer.flush().map_err(|e| Error::from(e).in_file(path))
+}
+
+/// Load a board from a text snapshot in a file, identified by a path.
+pub fn load<G: Grid + Default>(path: &str) -> Result<G>
+where
+    G::Tile: Tile,
+{
+    let text = std::fs::read_to_string(path).map_err(|e| Error::from(e).in_file(path))?;
+    read_snapshot(&text).map_err(|e| e.in_file(path))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::{Board, DenseBoard, Rect};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    enum Seat {
+        #[default]
+        Floor,
+        Empty,
+        Taken,
+    }
+
+    impl Tile for Seat {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Seat::Floor),
+                'L' => Some(Seat::Empty),
+                '#' => Some(Seat::Taken),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Seat::Floor => '.',
+                Seat::Empty => 'L',
+                Seat::Taken => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn test_snapshot() -> Result<()> {
+        let mut board = DenseBoard::with_bounds(Rect::new((-1, 2).into(), (4, 13).into()));
+        board.fill(&Rect::new((0, 3).into(), (0, 5).into()), Seat::Taken);
+        board.set(&(0, 13).into(), Seat::Empty);
+
+        let mut out = Vec::new();
+        write_snapshot(&board, &mut out)?;
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text, "snapshot 6x12 at -1,2\n12.\n.3#7.L\n12. *4\n");
+
+        let read: DenseBoard<Seat> = read_snapshot(&text)?;
+        assert_eq!(read, board);
+        let sparse: Board<Seat> = read_snapshot(&text)?;
+        assert_eq!(sparse.render(), board.render());
+
+        let mut out = Vec::new();
+        write_snapshot(&Board::<Seat>::new(), &mut out)?;
+        assert_eq!(out, b"snapshot empty\n");
+        assert_eq!(
+            read_snapshot::<Board<Seat>>("snapshot empty\n")?.bounds(),
+            None
+        );
+
+        let e = read_snapshot::<Board<Seat>>("snapshot 1x3 at 0,0\n2.x\n").unwrap_err();
+        assert_eq!(e.to_string(), "2:3: Unknown tile 'x'\n  | 2.x\n  |   ^");
+
+        let e = read_snapshot::<Board<Seat>>("snapshot 1x1 at 0,0\n. *0\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "2: Repeat count must be at least 1\n  | . *0"
+        );
+
+        let e = read_snapshot::<Board<Seat>>("snapshot 1x1 at 0,0\n. *100000000\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "2: Expected 1 rows, found more\n  | . *100000000"
+        );
+        assert!(read_snapshot::<Board<Seat>>("snapshot 2x3 at 0,0\n3.\n").is_err());
+        assert!(read_snapshot::<Board<Seat>>("snapshot 1x3 at 0,0\n4.\n").is_err());
+        assert!(read_snapshot::<Board<Seat>>("snapshot 1x3 at 0,0\n3\n").is_err());
+        assert!(read_snapshot::<Board<Seat>>("snapshot 1by3 at 0,0\n3.\n").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::solution::Solution;
+use crate::{state_err, Result};
+
+/// Follow the floor instructions to the end and return the final floor.
+///
+/// Only the current floor is kept, so the characters can come straight from a reader such as
+/// `util::stream_chars`.
+pub fn final_floor<I: IntoIterator<Item = Result<char>>>(chars: I) -> Result<i64> {
+    let mut floor = 0i64;
+    for c in chars {
+        match c? {
+            '(' => floor += 1,
+            ')' => floor -= 1,
+            _ => {}
+        }
+    }
+
+    Ok(floor)
+}
+
+/// Follow the floor instructions until the basement is entered and return the 1-based position of
+/// the instruction that got there.
+pub fn basement_position<I: IntoIterator<Item = Result<char>>>(chars: I) -> Result<usize> {
+    let mut floor = 0i64;
+    for (i, c) in chars.into_iter().enumerate() {
+        match c? {
+            '(' => floor += 1,
+            ')' => floor -= 1,
+            _ => {}
+        }
+
+        if floor < 0 {
+            return Ok(i + 1);
+        }
+    }
+
+    Err(state_err!("Santa never enters the basement"))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+    type AnswerOne = i64;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(data: &String) -> Result<i64> {
+        final_floor(data.chars().map(Ok))
+    }
+
+    fn part_two(data: &String) -> Result<usize> {
+        basement_position(data.chars().map(Ok))
+    }
+}
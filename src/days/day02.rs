@@ -1,8 +1,9 @@
-use aoc2015::util::read_to_parsed_lines;
-use aoc2015::{format_err, Error, Result};
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, Error, Result};
 
 #[derive(Debug)]
-struct Box {
+pub struct Box {
     l: i32,
     w: i32,
     h: i32,
@@ -49,14 +50,14 @@ impl std::str::FromStr for Box {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
         let mut tokens: Vec<i32> = s
-            .split("x")
+            .split('x')
             .map(|t| t.parse().map_err(|e: std::num::ParseIntError| e.into()))
             .collect::<Result<Vec<i32>>>()?;
 
         tokens.sort();
 
         if tokens.len() != 3 {
-            return Err(format_err!("Invalid number of tokens: '{}'", s));
+            return Err(parse_err!("Invalid number of tokens: '{}'", s));
         }
 
         let l = tokens[0];
@@ -67,16 +68,22 @@ impl std::str::FromStr for Box {
     }
 }
 
-fn main() -> Result<()> {
-    let data: Vec<Box> = read_to_parsed_lines("data/day02/input", &|l: &str| l.parse())?;
+pub struct Day02;
 
-    println!("Boxes: {:#?}", data);
+impl Solution for Day02 {
+    type Input = Vec<Box>;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
 
-    let paper_needed: i32 = data.iter().map(|b| b.wrapping()).sum();
-    let ribbon_needed: i32 = data.iter().map(|b| b.ribbon()).sum();
+    fn parse(input: &str) -> Result<Vec<Box>> {
+        parse_lines(input, |l| l.parse())
+    }
 
-    println!("Paper needed: {} sq ft", paper_needed);
-    println!("Ribbon needed: {} ft", ribbon_needed);
+    fn part_one(data: &Vec<Box>) -> Result<i32> {
+        Ok(data.iter().map(|b| b.wrapping()).sum())
+    }
 
-    Ok(())
+    fn part_two(data: &Vec<Box>) -> Result<i32> {
+        Ok(data.iter().map(|b| b.ribbon()).sum())
+    }
 }
@@ -0,0 +1,55 @@
+use crate::board::{Direction, Position};
+use crate::solution::Solution;
+use crate::{state_err, Result};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// Walk a number of walkers in turns, each taking the next step in the sequence, and collect all
+/// visited houses. Whitespace between steps is ignored.
+pub fn walk<I: IntoIterator<Item = Result<char>>>(
+    walkers: usize,
+    steps: I,
+) -> Result<HashSet<Position>> {
+    if walkers == 0 {
+        return Err(state_err!("Need at least one walker"));
+    }
+
+    let mut pos = vec![Position::ZERO; walkers];
+    let mut cur_walker = 0;
+    let mut seen = HashSet::new();
+    seen.insert(Position::ZERO);
+
+    for c in steps {
+        let c = c?;
+        if c.is_whitespace() {
+            continue;
+        }
+
+        pos[cur_walker] += Direction::try_from(c)?.into();
+        seen.insert(pos[cur_walker]);
+
+        cur_walker = (cur_walker + 1) % pos.len();
+    }
+
+    Ok(seen)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(steps: &String) -> Result<usize> {
+        Ok(walk(1, steps.chars().map(Ok))?.len())
+    }
+
+    fn part_two(steps: &String) -> Result<usize> {
+        Ok(walk(2, steps.chars().map(Ok))?.len())
+    }
+}
@@ -1,5 +1,5 @@
-use aoc2015::util::read_to_string;
-use aoc2015::Result;
+use crate::solution::Solution;
+use crate::Result;
 use md5::Digest;
 
 fn check_5(prefix: &str, n: usize) -> bool {
@@ -34,21 +34,32 @@ fn check_6(prefix: &str, n: usize) -> bool {
     true
 }
 
-fn main() -> Result<()> {
-    let data = read_to_string("data/day04/input")?.trim().to_owned();
+pub struct Day04;
 
-    let mut n = 0;
-    while !check_5(&data, n) {
-        n += 1;
+impl Solution for Day04 {
+    type Input = String;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.trim().to_owned())
     }
 
-    println!("First result: {}", n);
+    fn part_one(data: &String) -> Result<usize> {
+        let mut n = 0;
+        while !check_5(data, n) {
+            n += 1;
+        }
 
-    while !check_6(&data, n) {
-        n += 1;
+        Ok(n)
     }
 
-    println!("Second result: {}", n);
+    fn part_two(data: &String) -> Result<usize> {
+        let mut n = 0;
+        while !check_6(data, n) {
+            n += 1;
+        }
 
-    Ok(())
+        Ok(n)
+    }
 }
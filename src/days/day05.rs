@@ -0,0 +1,112 @@
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, Result};
+
+fn nice_vowels(s: &str) -> bool {
+    let mut vowels = 0;
+    for c in s.chars() {
+        match c {
+            'a' | 'e' | 'i' | 'o' | 'u' => vowels += 1,
+            _ => {}
+        }
+    }
+
+    vowels >= 3
+}
+
+fn nice_repetition(s: &str) -> bool {
+    s.as_bytes().windows(2).any(|w| w[0] == w[1])
+}
+
+const BAD_WORDS: [&str; 4] = ["ab", "cd", "pq", "xy"];
+
+fn nice_no_bad_words(s: &str) -> bool {
+    for bw in BAD_WORDS.iter() {
+        if s.contains(bw) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn nice1(s: &str) -> bool {
+    nice_vowels(s) && nice_repetition(s) && nice_no_bad_words(s)
+}
+
+fn nice_repeat_pair(s: &str) -> bool {
+    let pairs: Vec<&[u8]> = s.as_bytes().windows(2).collect();
+
+    for (i, ab) in pairs.iter().enumerate() {
+        if pairs.iter().skip(i + 2).any(|bc| bc == ab) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn nice_gap_letter(s: &str) -> bool {
+    s.as_bytes().windows(3).any(|w| w[0] == w[2])
+}
+
+fn parse_string(s: &str) -> Result<String> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(parse_err!("Expected a string of lowercase letters"));
+    }
+
+    Ok(s.to_owned())
+}
+
+fn nice2(s: &str) -> bool {
+    nice_repeat_pair(s) && nice_gap_letter(s)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<String>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_lines(input, parse_string)
+    }
+
+    fn part_one(strings: &Vec<String>) -> Result<usize> {
+        Ok(strings.iter().filter(|s| nice1(s)).count())
+    }
+
+    fn part_two(strings: &Vec<String>) -> Result<usize> {
+        Ok(strings.iter().filter(|s| nice2(s)).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let e = Day05::parse("abc\n\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "2: Expected a string of lowercase letters\n  | "
+        );
+        assert!(Day05::parse("abé").is_err());
+    }
+
+    #[test]
+    fn test_short_strings() -> Result<()> {
+        let strings = Day05::parse("x\nxx\nxxx")?;
+        assert_eq!(Day05::part_one(&strings)?, 0);
+        assert_eq!(Day05::part_two(&strings)?, 0);
+
+        assert!(nice2("qjhvhtzxzqqjkmpb"));
+        assert!(nice2("aaaa"));
+        assert!(!nice2("aaa"));
+
+        Ok(())
+    }
+}
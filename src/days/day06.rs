@@ -1,20 +1,16 @@
-use aoc2015::board::{Board, Direction, Position};
-use aoc2015::util::read_to_parsed_lines;
-use aoc2015::{format_err, Error, Result};
+use crate::board::{DenseBoard, Grid, Position, Rect};
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, Error, Result};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
 enum TileV1 {
+    #[default]
     Unknown,
     On,
     Off,
 }
 
-impl std::default::Default for TileV1 {
-    fn default() -> Self {
-        TileV1::Unknown
-    }
-}
-
 impl std::fmt::Display for TileV1 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         let c = match self {
@@ -49,7 +45,7 @@ impl std::str::FromStr for CommandV1 {
             "turn_on" => Ok(CommandV1::On),
             "turn_off" => Ok(CommandV1::Off),
             "toggle" => Ok(CommandV1::Toggle),
-            _ => Err(format_err!("invalid command: {}", s)),
+            _ => Err(parse_err!("invalid command: {}", s)),
         }
     }
 }
@@ -71,19 +67,12 @@ impl Cmd for CommandV1 {
 #[derive(Debug)]
 struct Instruction<C> {
     cmd: C,
-    from: Position,
-    to: Position,
+    rect: Rect,
 }
 
 impl<C: Cmd> Instruction<C> {
-    fn apply_to(&self, board: &mut Board<<C as Cmd>::TileType>) {
-        for i in self.from.i..=self.to.i {
-            for j in self.from.j..=self.to.j {
-                let t = board.get(&Position { i, j });
-                let u = self.cmd.apply_to(&t);
-                board.set(&Position { i, j }, u);
-            }
-        }
+    fn apply_to<G: Grid<Tile = C::TileType>>(&self, board: &mut G) {
+        board.map_region(&self.rect, |t| self.cmd.apply_to(&t));
     }
 }
 
@@ -93,11 +82,11 @@ impl std::str::FromStr for Pos {
     type Err = Error;
     fn from_str(s: &str) -> Result<Pos> {
         let tokens: Vec<i64> = s
-            .split(",")
+            .split(',')
             .map(|v| v.parse().map_err(|e: std::num::ParseIntError| e.into()))
             .collect::<Result<Vec<i64>>>()?;
         if tokens.len() != 2 {
-            return Err(format_err!("Position {} has invalid number of tokens", s));
+            return Err(parse_err!("Position {} has invalid number of tokens", s));
         }
 
         Ok(Pos(Position {
@@ -111,9 +100,9 @@ impl<C: std::str::FromStr<Err = Error>> std::str::FromStr for Instruction<C> {
     type Err = Error;
     fn from_str(s: &str) -> Result<Instruction<C>> {
         let s = s.replace("turn ", "turn_");
-        let tokens: Vec<&str> = s.split(" ").collect();
+        let tokens: Vec<&str> = s.split(' ').collect();
         if tokens.len() != 4 {
-            return Err(format_err!("Line {} has invalid number of tokens", s));
+            return Err(parse_err!("Line {} has invalid number of tokens", s));
         }
 
         let cmd = tokens[0].parse()?;
@@ -122,8 +111,7 @@ impl<C: std::str::FromStr<Err = Error>> std::str::FromStr for Instruction<C> {
 
         Ok(Instruction {
             cmd,
-            from: from.0,
-            to: to.0,
+            rect: Rect::new(from.0, to.0),
         })
     }
 }
@@ -133,7 +121,7 @@ struct CommandV2 {
     amount: i64,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 struct TileV2(i64);
 
 impl std::fmt::Display for TileV2 {
@@ -142,16 +130,10 @@ impl std::fmt::Display for TileV2 {
     }
 }
 
-impl std::default::Default for TileV2 {
-    fn default() -> Self {
-        TileV2(0)
-    }
-}
-
 impl Cmd for CommandV2 {
     type TileType = TileV2;
     fn apply_to(&self, t: &TileV2) -> TileV2 {
-        let mut u = t.clone();
+        let mut u = *t;
         u.0 += self.amount;
 
         if u.0 < 0 {
@@ -169,37 +151,57 @@ impl std::str::FromStr for CommandV2 {
             "turn_on" => Ok(CommandV2 { amount: 1 }),
             "turn_off" => Ok(CommandV2 { amount: -1 }),
             "toggle" => Ok(CommandV2 { amount: 2 }),
-            _ => Err(format_err!("invalid command: {}", s)),
+            _ => Err(parse_err!("invalid command: {}", s)),
         }
     }
 }
 
-fn main() -> Result<()> {
-    println!("PART ONE");
-    let instrs: Vec<Instruction<CommandV1>> =
-        read_to_parsed_lines("data/day06/input", &|l: &str| l.parse())?;
+/// The region covered by the light grid.
+const GRID: Rect = Rect {
+    min: Position { i: 0, j: 0 },
+    max: Position { i: 999, j: 999 },
+};
+
+/// The light instructions, interpreted both in the on/off and in the brightness sense.
+#[derive(Debug)]
+pub struct Instructions {
+    v1: Vec<Instruction<CommandV1>>,
+    v2: Vec<Instruction<CommandV2>>,
+}
+
+pub struct Day06;
 
-    let mut board = Board::new();
+impl Solution for Day06 {
+    type Input = Instructions;
+    type AnswerOne = usize;
+    type AnswerTwo = i64;
 
-    for instr in instrs.iter() {
-        instr.apply_to(&mut board);
-    }
+    fn parse(input: &str) -> Result<Instructions> {
+        let v1 = parse_lines(input, |l| l.parse())?;
+        let v2 = parse_lines(input, |l| l.parse())?;
 
-    let counts = board.count();
+        Ok(Instructions { v1, v2 })
+    }
 
-    println!("Counts: {:?}", counts);
+    fn part_one(instrs: &Instructions) -> Result<usize> {
+        let mut board = DenseBoard::with_bounds(GRID);
 
-    println!("\nPART TWO");
-    let instrs: Vec<Instruction<CommandV2>> =
-        read_to_parsed_lines("data/day06/input", &|l: &str| l.parse())?;
+        for instr in instrs.v1.iter() {
+            instr.apply_to(&mut board);
+        }
 
-    let mut board = Board::new();
+        let counts = board.count();
 
-    for instr in instrs.iter() {
-        instr.apply_to(&mut board);
+        Ok(counts.get(&TileV1::On).copied().unwrap_or(0))
     }
 
-    let brightness: i64 = board.tiles.values().map(|v| v.0).sum();
-    println!("Total brightness: {}", brightness);
-    Ok(())
+    fn part_two(instrs: &Instructions) -> Result<i64> {
+        let mut board = DenseBoard::with_bounds(GRID);
+
+        for instr in instrs.v2.iter() {
+            instr.apply_to(&mut board);
+        }
+
+        Ok(board.iter().map(|(_, v)| v.0).sum())
+    }
 }
@@ -0,0 +1,439 @@
+use crate::solution::Solution;
+use crate::{parse_err, state_err, Error, Result};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+enum Source {
+    Static { v: u16 },
+    Wire { id: String },
+}
+
+impl Source {
+    /// Get the value of the current source, if it can be known from the solved wires, or None.
+    fn get_value(&self, values: &HashMap<String, u16>) -> Option<u16> {
+        match self {
+            Source::Static { v } => Some(*v),
+            Source::Wire { id } => values.get(id).copied(),
+        }
+    }
+
+    /// Get the wire this source reads from, if any.
+    fn wire(&self) -> Option<&str> {
+        match self {
+            Source::Static { .. } => None,
+            Source::Wire { id } => Some(id),
+        }
+    }
+}
+
+impl std::fmt::Debug for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            Source::Static { v } => write!(f, "{}", v),
+            Source::Wire { id } => write!(f, "{}", id),
+        }
+    }
+}
+
+impl std::str::FromStr for Source {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        if let Ok(v) = s.parse() {
+            Ok(Source::Static { v })
+        } else {
+            Ok(Source::Wire { id: s.to_owned() })
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Wiring {
+    Assign { src: Source },
+    Not { src: Source },
+    And { a: Source, b: Source },
+    Or { a: Source, b: Source },
+    LShift { src: Source, amount: Source },
+    RShift { src: Source, amount: Source },
+}
+
+impl Wiring {
+    /// Get the signal this wiring drives onto wire `id`, once all its sources are solved.
+    fn get_value(&self, id: &str, values: &HashMap<String, u16>) -> Result<u16> {
+        let get = |src: &Source| {
+            src.get_value(values)
+                .ok_or_else(|| state_err!("Could not determine signal for wire '{}'", id))
+        };
+        let shift = |amount: &Source| {
+            let amount = get(amount)?;
+            if u32::from(amount) >= u16::BITS {
+                return Err(state_err!(
+                    "Cannot shift a {}-bit signal by {} for wire '{}'",
+                    u16::BITS,
+                    amount,
+                    id
+                ));
+            }
+            Ok(u32::from(amount))
+        };
+
+        match self {
+            Wiring::Assign { src } => get(src),
+            Wiring::Not { src } => Ok(!get(src)?),
+            Wiring::And { a, b } => Ok(get(a)? & get(b)?),
+            Wiring::Or { a, b } => Ok(get(a)? | get(b)?),
+            Wiring::LShift { src, amount } => Ok(get(src)? << shift(amount)?),
+            Wiring::RShift { src, amount } => Ok(get(src)? >> shift(amount)?),
+        }
+    }
+
+    /// Get the name of the gate of this wiring, or None for a plain assignment.
+    fn gate(&self) -> Option<&'static str> {
+        match self {
+            Wiring::Assign { .. } => None,
+            Wiring::Not { .. } => Some("NOT"),
+            Wiring::And { .. } => Some("AND"),
+            Wiring::Or { .. } => Some("OR"),
+            Wiring::LShift { .. } => Some("LSHIFT"),
+            Wiring::RShift { .. } => Some("RSHIFT"),
+        }
+    }
+
+    /// Get the sources feeding into this wiring.
+    fn sources(&self) -> Vec<&Source> {
+        match self {
+            Wiring::Assign { src } | Wiring::Not { src } => vec![src],
+            Wiring::And { a, b } | Wiring::Or { a, b } => vec![a, b],
+            Wiring::LShift { src, amount } | Wiring::RShift { src, amount } => vec![src, amount],
+        }
+    }
+}
+
+impl std::str::FromStr for Wiring {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let tokens: Vec<_> = s.trim().split(' ').collect();
+
+        match tokens[..] {
+            [src] => Ok(Wiring::Assign { src: src.parse()? }),
+            ["NOT", src] => Ok(Wiring::Not { src: src.parse()? }),
+            [a, "AND", b] => Ok(Wiring::And {
+                a: a.parse()?,
+                b: b.parse()?,
+            }),
+            [a, "OR", b] => Ok(Wiring::Or {
+                a: a.parse()?,
+                b: b.parse()?,
+            }),
+            [src, "LSHIFT", amount] => Ok(Wiring::LShift {
+                src: src.parse()?,
+                amount: amount.parse()?,
+            }),
+            [src, "RSHIFT", amount] => Ok(Wiring::RShift {
+                src: src.parse()?,
+                amount: amount.parse()?,
+            }),
+            _ => Err(parse_err!("Unknown wiring spec: '{}'", s)),
+        }
+    }
+}
+
+impl std::fmt::Debug for Wiring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            Wiring::Assign { src } => write!(f, "{:?}", src),
+            Wiring::Not { src } => write!(f, "!{:?}", src),
+            Wiring::And { a, b } => write!(f, "{:?} AND {:?}", a, b),
+            Wiring::Or { a, b } => write!(f, "{:?} AND {:?}", a, b),
+            Wiring::LShift { src, amount } => write!(f, "{:?} << {:?}", src, amount),
+            Wiring::RShift { src, amount } => write!(f, "{:?} >> {:?}", src, amount),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    wires: HashMap<String, Wiring>,
+}
+
+impl Circuit {
+    /// Compute the signal on a wire, along with every wire it depends on, evaluating each wire
+    /// only after its inputs. Signals already in `values` are reused.
+    ///
+    /// Fails naming the wires involved if they form a cycle, or if a wire is read but never
+    /// driven.
+    fn evaluate(&self, id: &str, values: &mut HashMap<String, u16>) -> Result<u16> {
+        // the wires being evaluated, each one an input of the one before
+        let mut stack: Vec<&str> = vec![id];
+
+        while let Some(&cur) = stack.last() {
+            if values.contains_key(cur) {
+                stack.pop();
+                continue;
+            }
+
+            let wiring = self.wires.get(cur).ok_or_else(|| match stack.len() {
+                1 => state_err!("No such wire: '{}'", cur),
+                n => state_err!(
+                    "Wire '{}' is read by '{}' but never driven",
+                    cur,
+                    stack[n - 2]
+                ),
+            })?;
+
+            let pending = wiring
+                .sources()
+                .into_iter()
+                .filter_map(|src| src.wire())
+                .find(|w| !values.contains_key(*w));
+
+            match pending {
+                Some(input) => {
+                    if let Some(start) = stack.iter().position(|w| *w == input) {
+                        let cycle: Vec<&str> = stack[start..].to_vec();
+                        return Err(state_err!(
+                            "Wires form a cycle: {} -> {}",
+                            cycle.join(" -> "),
+                            input
+                        ));
+                    }
+
+                    stack.push(input);
+                }
+                None => {
+                    let v = wiring.get_value(cur, values)?;
+                    values.insert(cur.to_owned(), v);
+                    stack.pop();
+                }
+            }
+        }
+
+        Ok(values[id])
+    }
+
+    /// Compute the signal on every wire.
+    pub fn solve(&self) -> Result<HashMap<String, u16>> {
+        let mut ids: Vec<&String> = self.wires.keys().collect();
+        ids.sort();
+
+        let mut values = HashMap::new();
+        for id in ids {
+            self.evaluate(id, &mut values)?;
+        }
+
+        Ok(values)
+    }
+
+    /// Draw the circuit as a graph in the Graphviz DOT format, with wires as ellipses, gates as
+    /// boxes and constants as highlighted boxes. Wires that are read but never driven are
+    /// dashed.
+    ///
+    /// Wires are labelled with their signals if `values` are given, e.g. from `solve`.
+    pub fn to_dot(&self, values: Option<&HashMap<String, u16>>) -> String {
+        let mut out = String::from("digraph circuit {\n    rankdir=LR;\n");
+
+        let mut ids: Vec<&String> = self.wires.keys().collect();
+        ids.sort();
+
+        let mut wires: Vec<&str> = ids.iter().map(|id| id.as_str()).collect();
+        for wiring in self.wires.values() {
+            wires.extend(wiring.sources().into_iter().filter_map(|src| src.wire()));
+        }
+        wires.sort_unstable();
+        wires.dedup();
+
+        for id in wires {
+            let label = match values.and_then(|v| v.get(id)) {
+                Some(v) => format!("{}\\n= {}", dot_escape(id), v),
+                None => dot_escape(id),
+            };
+            let style = if self.wires.contains_key(id) {
+                ""
+            } else {
+                ", style=dashed"
+            };
+            out += &format!(
+                "    \"{}\" [label=\"{}\"{}];\n",
+                dot_escape(id),
+                label,
+                style
+            );
+        }
+
+        for id in ids {
+            let wiring = &self.wires[id];
+
+            let target = match wiring.gate() {
+                Some(gate) => {
+                    let node = dot_escape(&format!("gate:{}", id));
+                    out += &format!("    \"{}\" [label=\"{}\", shape=box];\n", node, gate);
+                    out += &format!("    \"{}\" -> \"{}\";\n", node, dot_escape(id));
+                    node
+                }
+                None => dot_escape(id),
+            };
+
+            for (k, src) in wiring.sources().into_iter().enumerate() {
+                let node = match src {
+                    Source::Wire { id } => dot_escape(id),
+                    Source::Static { v } => {
+                        let node = dot_escape(&format!("const:{}:{}", id, k));
+                        out += &format!(
+                            "    \"{}\" [label=\"{}\", shape=box, style=filled, fillcolor=gold];\n",
+                            node, v
+                        );
+                        node
+                    }
+                };
+                out += &format!("    \"{}\" -> \"{}\";\n", node, target);
+            }
+        }
+
+        out += "}\n";
+        out
+    }
+}
+
+/// Escape a string for use inside a quoted DOT id or label.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Circuit {
+    fn from_instrs(s: &[String]) -> Result<Self> {
+        let mut wires = HashMap::new();
+
+        for (i, l) in s.iter().enumerate() {
+            let tokens: Vec<_> = l.split("->").collect();
+            if tokens.len() != 2 {
+                return Err(parse_err!("Expected one '->' in wire spec").on_line(i + 1, l));
+            }
+
+            let wiring: Wiring = tokens[0]
+                .trim()
+                .parse()
+                .map_err(|e: Error| e.on_line(i + 1, l))?;
+            let dst = tokens[1].trim().to_owned();
+
+            if wires.contains_key(&dst) {
+                return Err(parse_err!("Wire '{}' is driven twice", dst).on_line(i + 1, l));
+            }
+            wires.insert(dst, wiring);
+        }
+
+        Ok(Circuit { wires })
+    }
+}
+
+impl Circuit {
+    /// Get the signal on a wire.
+    fn signal(&self, id: &str) -> Result<u16> {
+        self.evaluate(id, &mut HashMap::new())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Circuit;
+    type AnswerOne = u16;
+    type AnswerTwo = u16;
+
+    fn parse(input: &str) -> Result<Circuit> {
+        let lines: Vec<String> = input.lines().map(|l| l.to_owned()).collect();
+        Circuit::from_instrs(&lines)
+    }
+
+    fn part_one(circuit: &Circuit) -> Result<u16> {
+        circuit.signal("a")
+    }
+
+    fn part_two(circuit: &Circuit) -> Result<u16> {
+        let v_a = Self::part_one(circuit)?;
+
+        let mut circuit = circuit.clone();
+        circuit.wires.insert(
+            "b".to_owned(),
+            Wiring::Assign {
+                src: Source::Static { v: v_a },
+            },
+        );
+
+        circuit.signal("a")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_evaluation() -> Result<()> {
+        let circuit = Day07::parse("x AND y -> d\n123 -> x\n456 -> y\nNOT x -> h\nd OR h -> a")?;
+        assert_eq!(circuit.signal("d")?, 72);
+        assert_eq!(circuit.signal("h")?, 65412);
+        assert_eq!(circuit.signal("a")?, 65484);
+
+        let e = circuit.signal("b").unwrap_err();
+        assert_eq!(e.to_string(), "No such wire: 'b'");
+
+        let undriven = Day07::parse("x AND y -> a\n1 -> x")?;
+        let e = undriven.signal("a").unwrap_err();
+        assert_eq!(e.to_string(), "Wire 'y' is read by 'a' but never driven");
+
+        let cyclic = Day07::parse("b -> a\nc OR 1 -> b\nNOT b -> c")?;
+        let e = cyclic.signal("a").unwrap_err();
+        assert_eq!(e.to_string(), "Wires form a cycle: b -> c -> b");
+        assert!(cyclic.solve().is_err());
+
+        let e = Day07::parse("1 -> a\nx -> y\n2 -> a").unwrap_err();
+        assert_eq!(e.to_string(), "3: Wire 'a' is driven twice\n  | 2 -> a");
+
+        let overflowing = Day07::parse("1 -> x\nx LSHIFT 16 -> a")?;
+        let e = overflowing.signal("a").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Cannot shift a 16-bit signal by 16 for wire 'a'"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dot() -> Result<()> {
+        let circuit = Day07::parse("x LSHIFT 2 -> a\n3 -> x\ny -> b")?;
+
+        assert_eq!(
+            circuit.to_dot(None),
+            r#"digraph circuit {
+    rankdir=LR;
+    "a" [label="a"];
+    "b" [label="b"];
+    "x" [label="x"];
+    "y" [label="y", style=dashed];
+    "gate:a" [label="LSHIFT", shape=box];
+    "gate:a" -> "a";
+    "x" -> "gate:a";
+    "const:a:1" [label="2", shape=box, style=filled, fillcolor=gold];
+    "const:a:1" -> "gate:a";
+    "y" -> "b";
+    "const:x:0" [label="3", shape=box, style=filled, fillcolor=gold];
+    "const:x:0" -> "x";
+}
+"#
+        );
+
+        let mut values = HashMap::new();
+        values.insert("a".to_owned(), circuit.signal("a")?);
+        assert!(circuit
+            .to_dot(Some(&values))
+            .contains(r#""a" [label="a\n= 12"];"#));
+
+        let quoted = Day07::parse(r#"b"\ -> a"#)?;
+        let dot = quoted.to_dot(None);
+        assert!(dot.contains(r#""b\"\\" [label="b\"\\", style=dashed];"#));
+        assert!(dot.contains(r#""b\"\\" -> "a";"#));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, Result};
+
+fn unescape(s: &str) -> Result<String> {
+    let inner = s
+        .strip_prefix('"')
+        .ok_or_else(|| parse_err!("String doesnt start with \""))?;
+    let inner = inner
+        .strip_suffix('"')
+        .ok_or_else(|| parse_err!("String doesnt end with \""))?;
+
+    let mut out = String::new();
+    let mut iter = inner.chars();
+
+    while let Some(c) = iter.next() {
+        if c == '\\' {
+            let et = iter
+                .next()
+                .ok_or_else(|| parse_err!("Unfinished escape at the end of the string"))?;
+            match et {
+                '\\' => out.push('\\'),
+                '"' => out.push('"'),
+                'x' => {
+                    let code: String = iter.by_ref().take(2).collect();
+                    if code.len() != 2 {
+                        return Err(parse_err!("Expected two hex digits after '\\x'"));
+                    }
+                    let code = u8::from_str_radix(&code, 16)?;
+                    out.push(code as char);
+                }
+                _ => return Err(parse_err!("Unknown escape code: '{}'", et)),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+
+    Ok(out)
+}
+
+fn escape(s: &str) -> Result<String> {
+    let mut out = String::new();
+    out.push('\"');
+
+    for c in s.chars() {
+        match c {
+            '"' => {
+                out.push('\\');
+                out.push('"');
+            }
+            '\\' => {
+                out.push('\\');
+                out.push('\\');
+            }
+            _ => {
+                out.push(c);
+            }
+        }
+    }
+
+    out.push('\"');
+    Ok(out)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<String>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_lines(input, |l| unescape(l).map(|_| l.to_owned()))
+    }
+
+    fn part_one(lines: &Vec<String>) -> Result<usize> {
+        let mut small_size = 0;
+        let mut mid_size = 0;
+        for l in lines.iter() {
+            let s: String = unescape(l)?;
+
+            mid_size += l.len();
+            small_size += s.chars().count();
+        }
+
+        Ok(mid_size - small_size)
+    }
+
+    fn part_two(lines: &Vec<String>) -> Result<usize> {
+        let mut mid_size = 0;
+        let mut big_size = 0;
+        for l in lines.iter() {
+            let e: String = escape(l)?;
+
+            mid_size += l.len();
+            big_size += e.len();
+        }
+
+        Ok(big_size - mid_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let strings = Day08::parse(
+            r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#,
+        )?;
+        assert_eq!(Day08::part_one(&strings)?, 12);
+        assert_eq!(Day08::part_two(&strings)?, 19);
+
+        for (line, error) in [
+            ("", "String doesnt start with \""),
+            (r#"""#, "String doesnt end with \""),
+            (r#""a\""#, "Unfinished escape at the end of the string"),
+            (r#""\x2""#, "Expected two hex digits after '\\x'"),
+        ] {
+            let e = Day08::parse(&format!("{}\n\"ok\"", line)).unwrap_err();
+            assert_eq!(e.to_string(), format!("1: {}\n  | {}", error, line));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::pattern::Pattern;
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{state_err, Result};
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+
+use permutohedron::LexicalPermutation;
+
+lazy_static! {
+    static ref CONNECTION: Pattern = Pattern::new("{from} to {to} = {distance}").unwrap();
+}
+
+fn parse_connection(s: &str) -> Result<((String, String), usize)> {
+    let c = CONNECTION.captures(s)?;
+
+    let mut places = [c.get("from")?.to_owned(), c.get("to")?.to_owned()];
+    places.sort();
+
+    let [a, b] = places;
+    Ok(((a, b), c.parse("distance")?))
+}
+
+fn calc_dist(connections: &HashMap<(String, String), usize>, route: &[String]) -> Option<usize> {
+    let mut total = 0;
+
+    let mut iter = route.iter();
+    let mut current = iter.next()?;
+
+    for next in iter {
+        if let Some(d) = connections
+            .get(&(current.to_string(), next.to_string()))
+            .or_else(|| connections.get(&(next.to_string(), current.to_string())))
+        {
+            total += d;
+            current = next;
+        } else {
+            return None;
+        }
+    }
+
+    Some(total)
+}
+
+fn get_places(connections: &HashMap<(String, String), usize>) -> Vec<String> {
+    let mut out: HashSet<String> = HashSet::new();
+    for (a, b) in connections.keys() {
+        out.insert(a.to_string());
+        out.insert(b.to_string());
+    }
+
+    out.into_iter().collect()
+}
+
+/// Calculate the distances of all possible routes visiting every place exactly once.
+fn route_distances(connections: &HashMap<(String, String), usize>) -> Vec<usize> {
+    let mut route = get_places(connections);
+    route.sort();
+
+    let mut distances = Vec::new();
+    loop {
+        if let Some(dist) = calc_dist(connections, &route[..]) {
+            distances.push(dist);
+        }
+
+        if !route.next_permutation() {
+            break;
+        }
+    }
+
+    distances
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = HashMap<(String, String), usize>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_connection)
+    }
+
+    fn part_one(connections: &Self::Input) -> Result<usize> {
+        route_distances(connections)
+            .into_iter()
+            .min()
+            .ok_or_else(|| state_err!("No route visits all places"))
+    }
+
+    fn part_two(connections: &Self::Input) -> Result<usize> {
+        route_distances(connections)
+            .into_iter()
+            .max()
+            .ok_or_else(|| state_err!("No route visits all places"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_no_places() -> Result<()> {
+        let connections = Day09::parse("")?;
+        assert!(Day09::part_one(&connections).is_err());
+        assert!(Day09::part_two(&connections).is_err());
+
+        Ok(())
+    }
+}
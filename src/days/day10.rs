@@ -0,0 +1,125 @@
+use crate::solution::Solution;
+use crate::{parse_err, Result};
+
+fn look_say(s: &[u8]) -> Vec<u8> {
+    let mut current: Option<u8> = None;
+    let mut count = 0;
+    let mut out = Vec::new();
+    for d in s {
+        match current {
+            Some(e) => {
+                if e == *d {
+                    count += 1;
+                } else {
+                    out.push(count);
+                    out.push(e);
+
+                    count = 1;
+                    current = Some(*d);
+                }
+            }
+            None => {
+                count = 1;
+                current = Some(*d);
+            }
+        }
+    }
+    out.push(count);
+    out.push(current.unwrap());
+
+    out
+}
+
+#[cfg(test)]
+fn to_str(s: &[u8]) -> String {
+    s.iter()
+        .map(|v| format!("{}", v))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+#[cfg(test)]
+fn to_digits(v: &usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut v = *v;
+
+    while v > 0 {
+        let digit = v % 10;
+        v /= 10;
+
+        out.insert(0, digit as u8);
+    }
+
+    out
+}
+
+fn look_say_length(start: &[u8], steps: usize) -> usize {
+    let mut val = start.to_vec();
+    for _ in 0..steps {
+        val = look_say(&val);
+    }
+
+    val.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u8>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<u8>> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(parse_err!("Expected a sequence of digits"));
+        }
+
+        input
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|d| d as u8)
+                    .ok_or_else(|| parse_err!("Invalid digit '{}'", c))
+            })
+            .collect()
+    }
+
+    fn part_one(val: &Vec<u8>) -> Result<usize> {
+        Ok(look_say_length(val, 40))
+    }
+
+    fn part_two(val: &Vec<u8>) -> Result<usize> {
+        Ok(look_say_length(val, 50))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_look_say() {
+        let tests = [
+            (1, "11"),
+            (11, "21"),
+            (21, "1211"),
+            (1211, "111221"),
+            (111221, "312211"),
+        ];
+
+        for (input, expected) in tests.iter() {
+            assert_eq!(to_str(&look_say(&to_digits(input))), *expected);
+        }
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(Day10::parse("1211\n")?, vec![1, 2, 1, 1]);
+        assert!(Day10::parse("").is_err());
+        assert!(Day10::parse("12a").is_err());
+
+        Ok(())
+    }
+}
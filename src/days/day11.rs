@@ -1,9 +1,10 @@
-use aoc2015::{format_err, Result};
+use crate::solution::Solution;
+use crate::{parse_err, state_err, Result};
 
 use std::collections::HashSet;
 
-#[derive(Debug)]
-struct Password {
+#[derive(Debug, Clone)]
+pub struct Password {
     value: Vec<u8>,
     bad_letters: HashSet<u8>,
     alphabet: String,
@@ -45,7 +46,7 @@ impl Password {
             }
         }
 
-        Err(format_err!("Cannot generate new password"))
+        Err(state_err!("Cannot generate new password"))
     }
 
     fn to_str(&self) -> String {
@@ -98,27 +99,70 @@ impl Password {
     fn meets_policy(&self) -> bool {
         self.has_straight3() && self.has_no_bad_letters() && self.has_two_pairs()
     }
+
+    /// Advance to the first password at or after the current one that meets the policy.
+    fn find_valid(&mut self) -> Result<String> {
+        while !self.meets_policy() {
+            self.inc()?;
+        }
+
+        Ok(self.to_str())
+    }
 }
 
-fn main() -> Result<()> {
-    let mut pass = Password::from_str("vzbxkghb", "abcdefghijklmnopqrstuvwxyz", "iol");
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
 
-    println!("Initial password: {:?}", pass);
+/// The number of letters in a password.
+const PASSWORD_LENGTH: usize = 8;
 
-    let mut found_passwords = 0;
-    loop {
-        if pass.meets_policy() {
-            println!("p {}", pass.to_str());
+pub struct Day11;
 
-            found_passwords += 1;
+impl Solution for Day11 {
+    type Input = Password;
+    type AnswerOne = String;
+    type AnswerTwo = String;
 
-            if found_passwords >= 2 {
-                break;
-            }
+    fn parse(input: &str) -> Result<Password> {
+        let input = input.trim();
+
+        if !input.chars().all(|c| ALPHABET.contains(c)) {
+            return Err(parse_err!("Invalid password: '{}'", input));
+        }
+
+        if input.len() != PASSWORD_LENGTH {
+            return Err(parse_err!(
+                "Expected a password of {} letters, got '{}'",
+                PASSWORD_LENGTH,
+                input
+            ));
         }
 
+        Ok(Password::from_str(input, ALPHABET, "iol"))
+    }
+
+    fn part_one(pass: &Password) -> Result<String> {
+        pass.clone().find_valid()
+    }
+
+    fn part_two(pass: &Password) -> Result<String> {
+        let mut pass = pass.clone();
+        pass.find_valid()?;
         pass.inc()?;
+        pass.find_valid()
     }
+}
+
+#[cfg(test)]
+mod tests {
 
-    Ok(())
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert!(Day11::parse("abcdefgh\n").is_ok());
+        assert!(Day11::parse("").is_err());
+        assert!(Day11::parse("a").is_err());
+        assert!(Day11::parse("abcdefghi").is_err());
+        assert!(Day11::parse("abcdEfgh").is_err());
+    }
 }
@@ -1,5 +1,5 @@
-use aoc2015::util::read_to_string;
-use aoc2015::{format_err, Error, Result};
+use crate::solution::Solution;
+use crate::Result;
 
 use serde_json::Value;
 
@@ -28,13 +28,22 @@ impl Sum for Value {
     }
 }
 
-fn main() -> Result<()> {
-    let data = read_to_string("data/day12/input")?;
+pub struct Day12;
 
-    let v: Value = serde_json::from_str(&data)?;
+impl Solution for Day12 {
+    type Input = Value;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
 
-    println!("Sum: {}", v.sum(false));
-    println!("Red-corrected sum: {}", v.sum(true));
+    fn parse(input: &str) -> Result<Value> {
+        Ok(serde_json::from_str(input)?)
+    }
+
+    fn part_one(v: &Value) -> Result<i64> {
+        Ok(v.sum(false))
+    }
 
-    Ok(())
+    fn part_two(v: &Value) -> Result<i64> {
+        Ok(v.sum(true))
+    }
 }
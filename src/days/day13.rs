@@ -0,0 +1,96 @@
+use crate::pattern::Pattern;
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, Result};
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+
+use permutohedron::LexicalPermutation;
+
+lazy_static! {
+    static ref PREFERENCE: Pattern =
+        Pattern::new("{a} would {verb} {score} happiness units by sitting next to {b}.").unwrap();
+}
+
+fn parse_preference(s: &str) -> Result<((String, String), i64)> {
+    let c = PREFERENCE.captures(s)?;
+
+    let score: i64 = c.parse("score")?;
+    let score = match c.get("verb")? {
+        "gain" => score,
+        "lose" => -score,
+        verb => {
+            return Err(parse_err!("Expected 'gain' or 'lose', got '{}'", verb)
+                .at_column(c.column("verb")?))
+        }
+    };
+
+    let a = c.get("a")?.to_owned();
+    let b = c.get("b")?.to_owned();
+
+    Ok(((a, b), score))
+}
+
+fn score_arrangement(arrangement: &[String], prefs: &HashMap<(String, String), i64>) -> i64 {
+    let mut score = 0;
+    for i in 0..arrangement.len() {
+        let a = arrangement[i].to_owned();
+        let b = arrangement[(i + 1) % arrangement.len()].to_owned();
+
+        score += prefs.get(&(a.clone(), b.clone())).unwrap_or(&0);
+        score += prefs.get(&(b.clone(), a.clone())).unwrap_or(&0);
+    }
+    score
+}
+
+fn find_max(arrangement: &mut [String], preferences: &HashMap<(String, String), i64>) -> i64 {
+    arrangement.sort();
+
+    let mut max_happiness = 0;
+    loop {
+        let happiness = score_arrangement(arrangement, preferences);
+
+        if happiness > max_happiness {
+            max_happiness = happiness;
+        }
+
+        if !arrangement.next_permutation() {
+            break;
+        }
+    }
+
+    max_happiness
+}
+
+fn get_people(preferences: &HashMap<(String, String), i64>) -> Vec<String> {
+    let mut people = HashSet::new();
+    for (a, b) in preferences.keys() {
+        people.insert(a.to_owned());
+        people.insert(b.to_owned());
+    }
+
+    people.into_iter().collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = HashMap<(String, String), i64>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_preference)
+    }
+
+    fn part_one(preferences: &Self::Input) -> Result<i64> {
+        let mut arrangement = get_people(preferences);
+        Ok(find_max(&mut arrangement, preferences))
+    }
+
+    fn part_two(preferences: &Self::Input) -> Result<i64> {
+        let mut arrangement = get_people(preferences);
+        arrangement.push("Self".to_owned());
+        Ok(find_max(&mut arrangement, preferences))
+    }
+}
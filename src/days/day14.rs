@@ -1,29 +1,33 @@
-use aoc2015::util::read_to_parsed_lines;
-use aoc2015::{format_err, Error, Result};
+use crate::pattern::Pattern;
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{state_err, Error, Result};
+use lazy_static::lazy_static;
 
 #[derive(Debug, Clone)]
-struct Reindeer {
+pub struct Reindeer {
     name: String,
     speed: usize,
     fly_duration: usize,
     rest_duration: usize,
 }
 
+lazy_static! {
+    static ref REINDEER: Pattern = Pattern::new(
+        "{name} can fly {speed} km/s for {fly} seconds, but then must rest for {rest} seconds."
+    )
+    .unwrap();
+}
+
 impl std::str::FromStr for Reindeer {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
-        // 0      1   2   3  4    5   6 7        8   9    10   11   12  13  14
-        // Dancer can fly 27 km/s for 5 seconds, but then must rest for 132 seconds.
-        let tokens: Vec<&str> = s.split(" ").collect();
-
-        if tokens.len() != 15 {
-            return Err(format_err!("Wrong number of tokens: '{}'", s));
-        }
+        let c = REINDEER.captures(s)?;
 
-        let name = tokens[0].to_owned();
-        let speed: usize = tokens[3].parse()?;
-        let fly_duration: usize = tokens[6].parse()?;
-        let rest_duration: usize = tokens[13].parse()?;
+        let name = c.get("name")?.to_owned();
+        let speed: usize = c.parse("speed")?;
+        let fly_duration: usize = c.parse("fly")?;
+        let rest_duration: usize = c.parse("rest")?;
 
         Ok(Reindeer {
             name,
@@ -106,9 +110,8 @@ impl std::fmt::Display for SimState {
     }
 }
 
-fn main() -> Result<()> {
-    let reindeers: Vec<Reindeer> = read_to_parsed_lines("data/day14/input", &|l| l.parse())?;
-
+/// Race the reindeer against each other for a number of seconds.
+fn simulate(reindeers: &[Reindeer], seconds: usize) -> Vec<SimState> {
     let mut states: Vec<SimState> = reindeers
         .iter()
         .map(|r| SimState {
@@ -122,19 +125,16 @@ fn main() -> Result<()> {
         })
         .collect();
 
-    for i in 1..=2503 {
-        println!("=== AFTER {:5} SECONDS ===", i);
+    for _ in 1..=seconds {
         for s in states.iter_mut() {
             s.step();
-            println!("{}", s);
         }
 
-        let mut max_dist = 0;
-        for s in states.iter() {
-            if s.distance_traveled > max_dist {
-                max_dist = s.distance_traveled;
-            }
-        }
+        let max_dist = states
+            .iter()
+            .map(|s| s.distance_traveled)
+            .max()
+            .unwrap_or(0);
 
         for s in states.iter_mut() {
             if s.distance_traveled == max_dist {
@@ -143,19 +143,64 @@ fn main() -> Result<()> {
         }
     }
 
-    println!("=== FINAL RANKING BY SCORE ===");
-    states.sort_by_key(|r| std::cmp::Reverse(r.distance_traveled));
-    for (i, s) in states.iter_mut().enumerate() {
-        s.step();
-        println!("#{}, {}", i + 1, s);
+    states
+}
+
+/// Get the distance covered by the reindeer that is furthest ahead after a number of seconds.
+fn winning_distance(reindeers: &[Reindeer], seconds: usize) -> Result<usize> {
+    simulate(reindeers, seconds)
+        .iter()
+        .map(|s| s.distance_traveled)
+        .max()
+        .ok_or_else(|| state_err!("No reindeer in the race"))
+}
+
+/// Get the points of the reindeer that was in the lead most often after a number of seconds.
+fn winning_score(reindeers: &[Reindeer], seconds: usize) -> Result<usize> {
+    simulate(reindeers, seconds)
+        .iter()
+        .map(|s| s.score)
+        .max()
+        .ok_or_else(|| state_err!("No reindeer in the race"))
+}
+
+const RACE_DURATION: usize = 2503;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Reindeer>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Reindeer>> {
+        parse_lines(input, |l| l.parse())
+    }
+
+    fn part_one(reindeers: &Vec<Reindeer>) -> Result<usize> {
+        winning_distance(reindeers, RACE_DURATION)
     }
 
-    println!("=== FINAL RANKING BY SCORE ===");
-    states.sort_by_key(|r| std::cmp::Reverse(r.score));
-    for (i, s) in states.iter_mut().enumerate() {
-        s.step();
-        println!("#{}, {}", i + 1, s);
+    fn part_two(reindeers: &Vec<Reindeer>) -> Result<usize> {
+        winning_score(reindeers, RACE_DURATION)
     }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
 
-    Ok(())
+    #[test]
+    fn test_example_race() -> Result<()> {
+        let reindeers = Day14::parse(
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+             Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.",
+        )?;
+
+        assert_eq!(winning_distance(&reindeers, 1000)?, 1120);
+        assert_eq!(winning_score(&reindeers, 1000)?, 689);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,152 @@
+use crate::pattern::Pattern;
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, Error, Result};
+use lazy_static::lazy_static;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ingredient {
+    name: String,
+    attributes: Vec<i64>,
+    calories: i64,
+}
+
+const ATTRIBUTES: [&str; 4] = ["capacity", "durability", "flavor", "texture"];
+
+lazy_static! {
+    static ref INGREDIENT: Pattern = Pattern::new(
+        "{name}: capacity {capacity}, durability {durability}, flavor {flavor}, texture {texture}, calories {calories}"
+    )
+    .unwrap();
+}
+
+impl std::str::FromStr for Ingredient {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let c = INGREDIENT.captures(s)?;
+
+        let name = c.get("name")?.to_owned();
+        let attributes = ATTRIBUTES
+            .iter()
+            .map(|a| c.parse(a))
+            .collect::<Result<_>>()?;
+        let calories: i64 = c.parse("calories")?;
+
+        Ok(Ingredient {
+            name,
+            attributes,
+            calories,
+        })
+    }
+}
+
+fn score(recipe: &HashMap<&Ingredient, i64>) -> (i64, i64) {
+    let n_attributes = recipe.keys().next().unwrap().attributes.len();
+
+    let mut attributes: Vec<i64> = (0..n_attributes).map(|_v| 0).collect();
+    let mut calories = 0;
+
+    for (ingredient, quantity) in recipe.iter() {
+        for (i, v) in ingredient.attributes.iter().enumerate() {
+            attributes[i] += quantity * v;
+        }
+
+        calories += quantity * ingredient.calories;
+    }
+
+    (
+        attributes.iter().map(|a| std::cmp::max(*a, 0)).product(),
+        calories,
+    )
+}
+
+/// Find the best score of any 100-teaspoon recipe, optionally constrained to a calorie count.
+fn best_score(ingredients: &[Ingredient], target_calories: Option<i64>) -> Result<i64> {
+    if ingredients.is_empty() {
+        return Err(parse_err!("Expected at least one ingredient"));
+    }
+
+    let mut recipe = HashMap::new();
+    Ok(best_remaining(
+        ingredients,
+        100,
+        &mut recipe,
+        target_calories,
+    ))
+}
+
+/// Find the best score when distributing the remaining teaspoons over the remaining ingredients.
+fn best_remaining<'a>(
+    ingredients: &'a [Ingredient],
+    teaspoons: i64,
+    recipe: &mut HashMap<&'a Ingredient, i64>,
+    target_calories: Option<i64>,
+) -> i64 {
+    match ingredients {
+        [] => 0,
+        [last] => {
+            recipe.insert(last, teaspoons);
+
+            let (cur_score, cur_calories) = score(recipe);
+            if target_calories.map(|t| t == cur_calories).unwrap_or(true) {
+                cur_score
+            } else {
+                0
+            }
+        }
+        [first, rest @ ..] => {
+            let mut top_score = 0;
+            for n in 0..=teaspoons {
+                recipe.insert(first, n);
+
+                let cur_score = best_remaining(rest, teaspoons - n, recipe, target_calories);
+                top_score = std::cmp::max(top_score, cur_score);
+            }
+
+            top_score
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Ingredient>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(input: &str) -> Result<Vec<Ingredient>> {
+        parse_lines(input, |l| l.parse())
+    }
+
+    fn part_one(ingredients: &Vec<Ingredient>) -> Result<i64> {
+        best_score(ingredients, None)
+    }
+
+    fn part_two(ingredients: &Vec<Ingredient>) -> Result<i64> {
+        best_score(ingredients, Some(500))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_recipe_scoring() -> Result<()> {
+        let butterscotch =
+            "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8".parse()?;
+        let cinnamon =
+            "Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3".parse()?;
+
+        let mut recipe = HashMap::new();
+        recipe.insert(&butterscotch, 44);
+        recipe.insert(&cinnamon, 56);
+
+        assert_eq!(score(&recipe), (62842880, 520));
+
+        Ok(())
+    }
+}
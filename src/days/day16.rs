@@ -0,0 +1,134 @@
+use crate::pattern::Pattern;
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{state_err, Error, Result};
+use lazy_static::lazy_static;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Sue {
+    number: usize,
+    knowledge: HashMap<String, i64>,
+}
+
+lazy_static! {
+    static ref SUE: Pattern = Pattern::new("Sue {number}: {knowledge}").unwrap();
+    static ref COMPOUND: Pattern = Pattern::new("{compound}: {count}").unwrap();
+}
+
+/// Parse a comma-separated list of compounds and their counts, e.g. `cars: 9, akitas: 3`.
+fn parse_knowledge(s: &str) -> Result<HashMap<String, i64>> {
+    let mut knowledge = HashMap::new();
+    let mut column = 1;
+    for item in s.split(", ") {
+        let parse_item = || -> Result<(String, i64)> {
+            let c = COMPOUND.captures(item)?;
+            Ok((c.get("compound")?.to_owned(), c.parse("count")?))
+        };
+
+        let (compound, count) = parse_item().map_err(|e| e.within_column(column))?;
+        knowledge.insert(compound, count);
+
+        column += item.chars().count() + 2;
+    }
+
+    Ok(knowledge)
+}
+
+impl std::str::FromStr for Sue {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let c = SUE.captures(s)?;
+
+        let number: usize = c.parse("number")?;
+        let knowledge = parse_knowledge(c.get("knowledge")?)
+            .map_err(|e| e.within_column(c.column("knowledge").unwrap_or(1)))?;
+
+        Ok(Sue { number, knowledge })
+    }
+}
+
+impl Sue {
+    fn matches(&self, description: &HashMap<String, i64>) -> bool {
+        for (k, v) in description.iter() {
+            if let Some(vs) = self.knowledge.get(k) {
+                if v != vs {
+                    // the current Sue has a different number of items than in the description
+                    return false;
+                }
+            }
+        }
+
+        for ks in self.knowledge.keys() {
+            if !description.contains_key(ks) {
+                // the current Sue has something that is not in the description
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn matches_pt2(&self, description: &HashMap<String, i64>) -> bool {
+        for (k, v) in description.iter() {
+            if let Some(vs) = self.knowledge.get(k) {
+                if k == "cats" || k == "trees" {
+                    // current Sue has more than the specified amount
+                    if v >= vs {
+                        return false;
+                    }
+                } else if k == "pomeranians" || k == "goldfish" {
+                    // current Sue has fewer than the specified amount
+                    if v <= vs {
+                        return false;
+                    }
+                } else if v != vs {
+                    // the current Sue has a different number of items than in the description
+                    return false;
+                }
+            }
+        }
+
+        for ks in self.knowledge.keys() {
+            if !description.contains_key(ks) {
+                // the current Sue has something that is not in the description
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+const DESCRIPTION: &str = "children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1";
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Vec<Sue>, HashMap<String, i64>);
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let sues = parse_lines(input, |l| l.parse())?;
+
+        let description = parse_knowledge(DESCRIPTION)?;
+
+        Ok((sues, description))
+    }
+
+    fn part_one((sues, description): &Self::Input) -> Result<usize> {
+        sues.iter()
+            .find(|s| s.matches(description))
+            .map(|s| s.number)
+            .ok_or_else(|| state_err!("No Sue matches the description"))
+    }
+
+    fn part_two((sues, description): &Self::Input) -> Result<usize> {
+        sues.iter()
+            .find(|s| s.matches_pt2(description))
+            .map(|s| s.number)
+            .ok_or_else(|| state_err!("No Sue matches the description"))
+    }
+}
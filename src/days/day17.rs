@@ -0,0 +1,48 @@
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{state_err, Result};
+use itertools::Itertools;
+
+const TARGET_VOLUME: u16 = 150;
+
+/// Find the number of containers used by every combination that holds exactly the target volume.
+fn combination_sizes(jars: &[u16]) -> Vec<usize> {
+    let mut sizes = Vec::new();
+    for n in 1..=jars.len() {
+        for combo in jars.iter().combinations(n) {
+            let total_volume: u16 = combo.iter().map(|v| **v).sum();
+
+            if total_volume == TARGET_VOLUME {
+                sizes.push(combo.len());
+            }
+        }
+    }
+
+    sizes
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<u16>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<u16>> {
+        parse_lines(input, |l| Ok(l.parse()?))
+    }
+
+    fn part_one(jars: &Vec<u16>) -> Result<usize> {
+        Ok(combination_sizes(jars).len())
+    }
+
+    fn part_two(jars: &Vec<u16>) -> Result<usize> {
+        let sizes = combination_sizes(jars);
+        let min_number = sizes
+            .iter()
+            .min()
+            .ok_or_else(|| state_err!("No combination holds {} liters", TARGET_VOLUME))?;
+
+        Ok(sizes.iter().filter(|n| *n == min_number).count())
+    }
+}
@@ -0,0 +1,109 @@
+use crate::board::animation::Animation;
+use crate::board::automaton::{Automaton, Rule};
+use crate::board::{DenseBoard, Grid, Topology};
+use crate::solution::Solution;
+use crate::{state_err, Result};
+
+use std::io::Write;
+
+/// The number of steps the lights are animated for.
+pub const STEPS: usize = 100;
+
+fn count_on(board: &DenseBoard<bool>) -> usize {
+    board.count().get(&true).copied().unwrap_or(0)
+}
+
+/// Get the automaton animating the lights, optionally with the corners stuck on.
+fn lights(board: &DenseBoard<bool>, stuck: bool) -> Result<Automaton<bool>> {
+    let mut life = Automaton::new(Rule::conway());
+
+    if stuck {
+        let bounds = board
+            .bounds()
+            .ok_or_else(|| state_err!("The light grid is empty"))?;
+
+        for corner in bounds.corners().iter() {
+            life = life.pin(*corner, true);
+        }
+    }
+
+    Ok(life)
+}
+
+/// Count the lights that are on after animating a number of steps.
+fn lights_after(board: &DenseBoard<bool>, steps: usize) -> usize {
+    let life = Automaton::new(Rule::conway());
+
+    count_on(&life.run(board, steps))
+}
+
+/// Count the lights that are on after animating a number of steps with the corners stuck on.
+fn stuck_lights_after(board: &DenseBoard<bool>, steps: usize) -> Result<usize> {
+    Ok(count_on(&lights(board, true)?.run(board, steps)))
+}
+
+/// Draw every step of animating the lights, starting with the initial state, and count the
+/// lights that are on at the end.
+pub fn animate<W: Write>(
+    board: &DenseBoard<bool>,
+    steps: usize,
+    stuck: bool,
+    animation: &mut Animation<DenseBoard<bool>, W>,
+) -> Result<usize> {
+    let life = lights(board, stuck)?;
+
+    let mut board = life.run(board, 0);
+    animation.frame(&board)?;
+
+    for _ in 0..steps {
+        board = life.step(&board);
+        animation.frame(&board)?;
+    }
+
+    Ok(count_on(&board))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DenseBoard<bool>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<DenseBoard<bool>> {
+        Ok(DenseBoard::parse(input)?.with_topology(Topology::Bounded { edge: false }))
+    }
+
+    fn part_one(board: &DenseBoard<bool>) -> Result<usize> {
+        Ok(lights_after(board, STEPS))
+    }
+
+    fn part_two(board: &DenseBoard<bool>) -> Result<usize> {
+        stuck_lights_after(board, STEPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_example_animation() -> Result<()> {
+        let board = Day18::parse(".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..")?;
+
+        assert_eq!(lights_after(&board, 4), 4);
+        assert_eq!(stuck_lights_after(&board, 5)?, 17);
+
+        let mut animation = Animation::new(Vec::new());
+        assert_eq!(animate(&board, 4, false, &mut animation)?, 4);
+        let out = String::from_utf8(animation.into_inner()).unwrap();
+        assert!(out.starts_with("\x1b[2J\x1b[H.#.#.#\n"));
+        assert!(out.ends_with("\x1b[7;1H"));
+
+        let mut animation = Animation::new(Vec::new());
+        assert_eq!(animate(&board, 5, true, &mut animation)?, 17);
+
+        Ok(())
+    }
+}
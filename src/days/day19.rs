@@ -1,8 +1,9 @@
-use aoc2015::util::read_to_lines;
-use aoc2015::{format_err, Error, Result};
+use crate::solution::Solution;
+use crate::util::split_sections;
+use crate::{parse_err, state_err, Error, Result};
 use std::collections::HashSet;
 
-struct Reaction {
+pub struct Reaction {
     educt: String,
     product: String,
 }
@@ -19,11 +20,15 @@ impl std::str::FromStr for Reaction {
         let mut tokens: Vec<_> = s.split(" => ").collect();
 
         if tokens.len() != 2 {
-            return Err(format_err!("Invalid number of tokens: '{}'", s));
+            return Err(parse_err!("Invalid number of tokens: '{}'", s));
         }
 
-        let educt = tokens.remove(0).to_owned();
-        let product = tokens.remove(0).to_owned();
+        let educt = tokens.remove(0).trim().to_owned();
+        let product = tokens.remove(0).trim().to_owned();
+
+        if educt.is_empty() || product.is_empty() {
+            return Err(parse_err!("Empty side in reaction: '{}'", s));
+        }
 
         Ok(Reaction { educt, product })
     }
@@ -71,53 +76,24 @@ impl Reaction {
     }
 }
 
-fn parse() -> Result<(Vec<Reaction>, String)> {
-    let lines = read_to_lines("data/day19/input")?;
-
-    let mut rxns = Vec::new();
-    let mut state = None;
-
-    for l in lines {
-        if l.trim().len() == 0 {
-            continue;
-        }
+fn parse(input: &str) -> Result<(Vec<Reaction>, String)> {
+    let [rules, molecule] = split_sections(input)?;
 
-        if let Ok(rxn) = l.parse() {
-            rxns.push(rxn)
-        } else {
-            state = Some(l.trim().to_owned());
-        }
-    }
+    let rxns = rules.parse_lines(|l| l.parse())?;
+    let state = molecule.parse_line(|l| Ok(l.trim().to_owned()))?;
 
-    if let Some(state) = state {
-        Ok((rxns, state))
-    } else {
-        Err(format_err!("Did not find an initial state!"))
-    }
+    Ok((rxns, state))
 }
 
-fn retrosynthesis(rxns: &Vec<Reaction>, initial: &str, target: &str) -> Option<usize> {
+fn retrosynthesis(rxns: &[Reaction], initial: &str, target: &str) -> Option<usize> {
     let mut queue = vec![(0, initial.to_owned())];
     let mut seen = HashSet::new();
 
-    let mut most_steps = 0;
-
     while !queue.is_empty() {
         queue.sort_by_key(|k| k.1.len());
 
         let (steps, cur) = queue.remove(0);
 
-        if steps > most_steps {
-            println!(
-                "Searching {}-step reactions, seen {} products so far",
-                steps,
-                seen.len()
-            );
-            most_steps = steps;
-        }
-
-        println!("{}", cur);
-
         if cur == target {
             return Some(steps);
         }
@@ -140,24 +116,31 @@ fn retrosynthesis(rxns: &Vec<Reaction>, initial: &str, target: &str) -> Option<u
     None
 }
 
-fn main() -> Result<()> {
-    let (rxns, state) = parse()?;
-
-    println!("State: {}\nReactions:\n{:#?}", state, rxns);
+pub struct Day19;
 
-    let mut all_products = HashSet::new();
+impl Solution for Day19 {
+    type Input = (Vec<Reaction>, String);
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
 
-    for rxn in &rxns {
-        for prd in rxn.apply_to(&state) {
-            all_products.insert(prd);
-        }
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
     }
 
-    println!("PART ONE: Got {} products in one step", all_products.len());
+    fn part_one((rxns, state): &Self::Input) -> Result<usize> {
+        let mut all_products = HashSet::new();
 
-    if let Some(n) = retrosynthesis(&rxns, &state, "e") {
-        println!("PART TWO: Made target in {} steps", n);
+        for rxn in rxns {
+            for prd in rxn.apply_to(state) {
+                all_products.insert(prd);
+            }
+        }
+
+        Ok(all_products.len())
     }
 
-    Ok(())
+    fn part_two((rxns, state): &Self::Input) -> Result<usize> {
+        retrosynthesis(rxns, state, "e")
+            .ok_or_else(|| state_err!("Cannot synthesize '{}' from 'e'", state))
+    }
 }
@@ -0,0 +1,66 @@
+use crate::solution::Solution;
+use crate::Result;
+use integer_sqrt::IntegerSquareRoot;
+
+fn calc_factors(n: u64) -> Vec<u64> {
+    let mut facs = Vec::new();
+    let limit = n.integer_sqrt();
+
+    for k in 1..=limit {
+        if n.is_multiple_of(k) {
+            facs.push(k);
+
+            let l = n / k;
+            if l != k {
+                facs.push(n / k);
+            }
+        }
+    }
+
+    facs
+}
+
+fn calc_presents_part1(facs: &[u64]) -> u64 {
+    facs.iter().sum::<u64>() * 10
+}
+
+fn calc_presents_part2(facs: &[u64], house_no: u64) -> u64 {
+    facs.iter().filter(|k| house_no / **k < 50).sum::<u64>() * 11
+}
+
+/// Find the lowest house number that gets at least the target number of presents.
+fn first_house<F: Fn(&[u64], u64) -> u64>(target_gifts: u64, presents: F) -> u64 {
+    let mut house_no: u64 = 1;
+
+    loop {
+        let factors = calc_factors(house_no);
+
+        if presents(&factors, house_no) >= target_gifts {
+            return house_no;
+        }
+
+        house_no += 1;
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = u64;
+    type AnswerOne = u64;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<u64> {
+        Ok(input.trim().parse()?)
+    }
+
+    fn part_one(target_gifts: &u64) -> Result<u64> {
+        Ok(first_house(*target_gifts, |facs, _| {
+            calc_presents_part1(facs)
+        }))
+    }
+
+    fn part_two(target_gifts: &u64) -> Result<u64> {
+        Ok(first_house(*target_gifts, calc_presents_part2))
+    }
+}
@@ -1,9 +1,12 @@
-use aoc2015::Result;
-
-struct Character {
-    hp: i8,
-    damage: i8,
-    armor: i8,
+use crate::solution::Solution;
+use crate::util::parse_stats;
+use crate::{state_err, Error, Result};
+
+#[derive(Clone)]
+pub struct Character {
+    hp: i64,
+    damage: i64,
+    armor: i64,
     cost: u16,
 }
 
@@ -20,6 +23,20 @@ impl Character {
     }
 }
 
+impl std::str::FromStr for Character {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let [hp, damage, armor] = parse_stats(s, ["Hit Points", "Damage", "Armor"])?;
+
+        Ok(Character {
+            hp,
+            damage,
+            armor,
+            cost: 0,
+        })
+    }
+}
+
 impl std::fmt::Debug for Character {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         write!(
@@ -34,8 +51,8 @@ impl std::fmt::Debug for Character {
 struct Item {
     name: String,
     cost: u16,
-    damage: i8,
-    armor: i8,
+    damage: i64,
+    armor: i64,
 }
 
 impl std::fmt::Debug for Item {
@@ -62,7 +79,7 @@ fn parse_shop(items: &str) -> Result<Vec<Item>> {
     */
 
     let mut out = Vec::new();
-    for line in items.trim().split("\n") {
+    for line in items.trim().split('\n') {
         let line = line.trim();
         let name = line[0..11].trim().to_owned();
         let cost = line[11..15].trim().parse()?;
@@ -80,6 +97,7 @@ fn parse_shop(items: &str) -> Result<Vec<Item>> {
     Ok(out)
 }
 
+#[derive(PartialEq)]
 enum BattleOutcome {
     PlayerWins,
     BossWins,
@@ -88,16 +106,13 @@ enum BattleOutcome {
 fn battle(mut player: Character, mut boss: Character) -> (BattleOutcome, u16) {
     let mut round = 1;
     loop {
-        //println!("###  ROUND {}", round);
         player.attack(&mut boss);
-        //println!("Boss:   {:?}", boss);
 
         if boss.hp <= 0 {
             return (BattleOutcome::PlayerWins, round);
         }
 
         boss.attack(&mut player);
-        //println!("Player: {:?}", player);
         if player.hp <= 0 {
             return (BattleOutcome::BossWins, round);
         }
@@ -106,7 +121,8 @@ fn battle(mut player: Character, mut boss: Character) -> (BattleOutcome, u16) {
     }
 }
 
-fn main() -> Result<()> {
+/// Fight the boss with every possible loadout, collecting the outcome and the loadout cost.
+fn fight_all_loadouts(boss: &Character) -> Result<Vec<(BattleOutcome, u16)>> {
     let weapons = parse_shop(
         "
     Dagger        8     4       0
@@ -141,12 +157,7 @@ fn main() -> Result<()> {
    ",
     )?;
 
-    let mut cheapest_loadout = None;
-    let mut cheapest_price = std::u16::MAX;
-
-    let mut expensiest_loadout = None;
-    let mut expensiest_price = std::u16::MIN;
-
+    let mut out = Vec::new();
     for weapon in &weapons {
         for armor in &armors {
             for ring1 in &rings {
@@ -167,50 +178,58 @@ fn main() -> Result<()> {
                         player.buy(item);
                     }
 
-                    println!("\n\nA new challenger appears!\n{:?}", player);
-                    println!("{:#?}", loadout);
-
-                    let boss = Character {
-                        hp: 109,
-                        damage: 8,
-                        armor: 2,
-                        cost: 999,
-                    };
-
                     let cost = player.cost;
+                    let (outcome, _round) = battle(player, boss.clone());
 
-                    match battle(player, boss) {
-                        (BattleOutcome::PlayerWins, round) => {
-                            println!("Player wins in round {}!", round);
-                            if cost < cheapest_price {
-                                cheapest_loadout = Some(loadout);
-                                cheapest_price = cost;
-                            }
-                        }
-                        (BattleOutcome::BossWins, round) => {
-                            println!("Boss wins in round {}!", round);
-                            if cost > expensiest_price {
-                                expensiest_loadout = Some(loadout);
-                                expensiest_price = cost;
-                            }
-                        }
-                    }
+                    out.push((outcome, cost));
                 }
             }
         }
     }
 
-    println!(
-        "Cheapest victorious loadout at 💰{} was:\n{:#?}",
-        cheapest_price,
-        cheapest_loadout.unwrap()
-    );
+    Ok(out)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Character;
+    type AnswerOne = u16;
+    type AnswerTwo = u16;
+
+    fn parse(input: &str) -> Result<Character> {
+        input.parse()
+    }
+
+    fn part_one(boss: &Character) -> Result<u16> {
+        fight_all_loadouts(boss)?
+            .into_iter()
+            .filter(|(outcome, _)| *outcome == BattleOutcome::PlayerWins)
+            .map(|(_, cost)| cost)
+            .min()
+            .ok_or_else(|| state_err!("No loadout can beat the boss"))
+    }
+
+    fn part_two(boss: &Character) -> Result<u16> {
+        fight_all_loadouts(boss)?
+            .into_iter()
+            .filter(|(outcome, _)| *outcome == BattleOutcome::BossWins)
+            .map(|(_, cost)| cost)
+            .max()
+            .ok_or_else(|| state_err!("No loadout loses against the boss"))
+    }
+}
 
-    println!(
-        "Most expensive losing loadout at 💰{} was:\n{:#?}",
-        expensiest_price,
-        expensiest_loadout.unwrap()
-    );
+#[cfg(test)]
+mod tests {
 
-    Ok(())
+    use super::*;
+
+    #[test]
+    fn test_large_stats() -> Result<()> {
+        let boss = Day21::parse("Hit Points: 300\nDamage: 0\nArmor: 0")?;
+        assert_eq!(Day21::part_one(&boss)?, 8);
+
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
-use aoc2015::Result;
+use crate::solution::Solution;
+use crate::util::parse_stats;
+use crate::{state_err, Error, Result};
 use lazy_static::lazy_static;
 
 use std::collections::HashMap;
@@ -77,7 +79,7 @@ impl Effect {
             Effect::Shield => 6,
             Effect::Poison => 6,
             Effect::Recharge => 5,
-            Effect::HardModeDrain => std::usize::MAX,
+            Effect::HardModeDrain => usize::MAX,
         }
     }
 
@@ -121,7 +123,7 @@ impl ActiveEffects {
         }
 
         self.data.retain(|e, d| {
-            if *d <= 0 {
+            if *d == 0 {
                 e.end_applying_to(state);
                 false
             } else {
@@ -160,7 +162,7 @@ impl std::fmt::Debug for State {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-struct HealthState {
+pub struct HealthState {
     player_mana: i64,
     player_health: i64,
     player_armor: i64,
@@ -169,6 +171,22 @@ struct HealthState {
     boss_damage: i64,
 }
 
+impl std::str::FromStr for HealthState {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let [boss_health, boss_damage] = parse_stats(s, ["Hit Points", "Damage"])?;
+
+        Ok(HealthState {
+            player_mana: 500,
+            player_health: 50,
+            player_armor: 0,
+
+            boss_health,
+            boss_damage,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 enum TurnOutcome {
     PlayerWins,
@@ -176,6 +194,7 @@ enum TurnOutcome {
     Continue { state: State },
 }
 
+#[cfg(test)]
 impl TurnOutcome {
     fn unwrap(self) -> State {
         match self {
@@ -268,40 +287,43 @@ fn recurse(state: State, mana_spent: usize, current_min: usize) -> usize {
     min_mana
 }
 
-fn main() -> Result<()> {
-    let start_state = State {
-        active_effects: Default::default(),
-        health_state: HealthState {
-            player_mana: 500,
-            player_health: 50,
-            player_armor: 0,
+fn min_mana(health_state: &HealthState, hard_mode: bool) -> Result<usize> {
+    let mut active_effects = ActiveEffects::default();
+    if hard_mode {
+        active_effects
+            .data
+            .insert(Effect::HardModeDrain, usize::MAX);
+    }
 
-            boss_health: 58,
-            boss_damage: 9,
-        },
+    let start_state = State {
+        active_effects,
+        health_state: health_state.clone(),
     };
 
-    let min_mana = recurse(start_state, 0, std::usize::MAX);
-    println!("Part 1: Min mana: {:?}", min_mana);
+    match recurse(start_state, 0, usize::MAX) {
+        usize::MAX => Err(state_err!("No sequence of spells beats the boss")),
+        mana => Ok(mana),
+    }
+}
 
-    let mut hard_mode = HashMap::new();
-    hard_mode.insert(Effect::HardModeDrain, std::usize::MAX);
-    let start_state = State {
-        active_effects: ActiveEffects { data: hard_mode },
-        health_state: HealthState {
-            player_mana: 500,
-            player_health: 50,
-            player_armor: 0,
+pub struct Day22;
 
-            boss_health: 58,
-            boss_damage: 9,
-        },
-    };
+impl Solution for Day22 {
+    type Input = HealthState;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<HealthState> {
+        input.parse()
+    }
 
-    let min_mana = recurse(start_state, 0, std::usize::MAX);
-    println!("Part 2: Min mana: {:?}", min_mana);
+    fn part_one(health_state: &HealthState) -> Result<usize> {
+        min_mana(health_state, false)
+    }
 
-    Ok(())
+    fn part_two(health_state: &HealthState) -> Result<usize> {
+        min_mana(health_state, true)
+    }
 }
 
 #[cfg(test)]
@@ -376,4 +398,15 @@ mod tests {
 
         assert_eq!(turn5, TurnOutcome::PlayerWins);
     }
+
+    #[test]
+    fn test_unbeatable_boss() -> Result<()> {
+        let health_state = Day22::parse("Hit Points: 400\nDamage: 30")?;
+
+        let e = Day22::part_one(&health_state).unwrap_err();
+        assert_eq!(e.to_string(), "No sequence of spells beats the boss");
+        assert!(Day22::part_two(&health_state).is_err());
+
+        Ok(())
+    }
 }
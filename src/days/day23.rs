@@ -0,0 +1,211 @@
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{parse_err, state_err, Error, Result};
+
+#[derive(Debug, Default, Clone)]
+struct State {
+    reg_a: usize,
+    reg_b: usize,
+    ic: usize,
+}
+
+impl State {
+    fn get_reg(&self, register: &Register) -> &usize {
+        match register {
+            Register::A => &self.reg_a,
+            Register::B => &self.reg_b,
+        }
+    }
+
+    fn get_reg_mut(&mut self, register: &Register) -> &mut usize {
+        match register {
+            Register::A => &mut self.reg_a,
+            Register::B => &mut self.reg_b,
+        }
+    }
+
+    fn jump_by_offset(&mut self, offset: i64) -> Result<()> {
+        let new_ic = (self.ic as i64) + offset;
+        if new_ic < 0 {
+            return Err(state_err!(
+                "Jump by {} from instruction {} leaves the program",
+                offset,
+                self.ic
+            ));
+        }
+        self.ic = new_ic as usize;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl std::str::FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            _ => Err(parse_err!("Invalid register: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Hlf { r: Register },
+    Tpl { r: Register },
+    Inc { r: Register },
+    Jmp { offset: i64 },
+    Jie { r: Register, offset: i64 },
+    Jio { r: Register, offset: i64 },
+}
+
+/// Parse the arguments of a conditional jump, like `a, +2`.
+fn parse_register_and_offset(arg: &str) -> Result<(Register, i64)> {
+    let args: Vec<_> = arg.split(',').collect();
+    match args[..] {
+        [r, offset] => Ok((r.trim().parse()?, offset.trim().parse()?)),
+        _ => Err(parse_err!("Expected a register and an offset: {}", arg)),
+    }
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let (op, arg) = match (s.get(..3), s.get(3..)) {
+            (Some(op), Some(arg)) => (op, arg.trim()),
+            _ => return Err(parse_err!("Invalid instruction: {}", s)),
+        };
+
+        match op {
+            "hlf" => {
+                let r: Register = arg.parse()?;
+                Ok(Instruction::Hlf { r })
+            }
+            "tpl" => {
+                let r: Register = arg.parse()?;
+                Ok(Instruction::Tpl { r })
+            }
+            "inc" => {
+                let r: Register = arg.parse()?;
+                Ok(Instruction::Inc { r })
+            }
+            "jmp" => {
+                let offset: i64 = arg.parse()?;
+                Ok(Instruction::Jmp { offset })
+            }
+            "jie" => {
+                let (r, offset) = parse_register_and_offset(arg)?;
+                Ok(Instruction::Jie { r, offset })
+            }
+            "jio" => {
+                let (r, offset) = parse_register_and_offset(arg)?;
+                Ok(Instruction::Jio { r, offset })
+            }
+            _ => Err(parse_err!("Invalid instruction: {}", s)),
+        }
+    }
+}
+
+impl Instruction {
+    fn run(&self, state: &mut State) -> Result<()> {
+        match self {
+            Instruction::Hlf { r } => *state.get_reg_mut(r) /= 2,
+            Instruction::Tpl { r } => *state.get_reg_mut(r) *= 3,
+            Instruction::Inc { r } => *state.get_reg_mut(r) += 1,
+            Instruction::Jmp { offset } => {
+                return state.jump_by_offset(*offset);
+            }
+            Instruction::Jie { r, offset } => {
+                if state.get_reg(r).is_multiple_of(2) {
+                    return state.jump_by_offset(*offset);
+                }
+            }
+            Instruction::Jio { r, offset } => {
+                if *state.get_reg(r) == 1 {
+                    return state.jump_by_offset(*offset);
+                }
+            }
+        }
+        state.ic += 1;
+        Ok(())
+    }
+}
+
+fn run(instructions: &[Instruction], mut state: State) -> Result<State> {
+    while let Some(inst) = instructions.get(state.ic) {
+        inst.run(&mut state)?;
+    }
+
+    Ok(state)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Instruction>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse_lines(input, |l| l.parse())
+    }
+
+    fn part_one(instructions: &Vec<Instruction>) -> Result<usize> {
+        let state = run(instructions, Default::default())?;
+        Ok(state.reg_b)
+    }
+
+    fn part_two(instructions: &Vec<Instruction>) -> Result<usize> {
+        let state = run(
+            instructions,
+            State {
+                reg_a: 1,
+                ..Default::default()
+            },
+        )?;
+        Ok(state.reg_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_example_program() -> Result<()> {
+        let instructions: Vec<Instruction> = "inc a\njio a, +2\ntpl a\ninc a"
+            .lines()
+            .map(|l| l.parse())
+            .collect::<Result<_>>()?;
+
+        let state = run(&instructions, Default::default())?;
+        assert_eq!(state.reg_a, 2);
+
+        let e = Day23::parse("inc a\nj").unwrap_err();
+        assert_eq!(e.to_string(), "2: Invalid instruction: j\n  | j");
+        assert!(Day23::parse("é").is_err());
+
+        let e = Day23::parse("jie a").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "1: Expected a register and an offset: a\n  | jie a"
+        );
+
+        let instructions = Day23::parse("inc a\njmp -2")?;
+        let e = Day23::part_one(&instructions).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Jump by -2 from instruction 1 leaves the program"
+        );
+
+        Ok(())
+    }
+}
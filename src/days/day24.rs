@@ -0,0 +1,88 @@
+use itertools::Itertools;
+
+use crate::solution::Solution;
+use crate::util::parse_lines;
+use crate::{state_err, Result};
+
+fn solve(parcels: &[usize], target_weight: usize) -> Option<(usize, usize)> {
+    let mut solution: Option<(usize, usize)> = None;
+    for group1_length in 1..parcels.len() {
+        for permutation in parcels.iter().combinations(group1_length) {
+            let group1_weight = permutation.iter().map(|n| **n).sum::<usize>();
+
+            if group1_weight != target_weight {
+                continue;
+            }
+
+            let group1 = &permutation[..group1_length];
+
+            let qe = group1.iter().map(|n| **n).product::<usize>();
+            let n_parcels = group1.len();
+
+            if let Some(sol) = solution {
+                if sol.0 > n_parcels || sol.1 > qe {
+                    solution = Some((n_parcels, qe))
+                }
+            } else {
+                solution = Some((n_parcels, qe))
+            }
+        }
+
+        if solution.is_some() {
+            break;
+        }
+    }
+
+    solution
+}
+
+/// Find the quantum entanglement of the ideal first group when splitting into equal groups.
+fn best_entanglement(parcels: &[usize], n_groups: usize) -> Result<usize> {
+    let total_weight = parcels.iter().sum::<usize>();
+    if total_weight % n_groups != 0 {
+        return Err(state_err!(
+            "Cannot split a total weight of {} into {} equal groups",
+            total_weight,
+            n_groups
+        ));
+    }
+
+    solve(parcels, total_weight / n_groups)
+        .map(|(_n_parcels, qe)| qe)
+        .ok_or_else(|| state_err!("Cannot split parcels into {} groups", n_groups))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<usize>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        parse_lines(input, |l| Ok(l.parse()?))
+    }
+
+    fn part_one(parcels: &Vec<usize>) -> Result<usize> {
+        best_entanglement(parcels, 3)
+    }
+
+    fn part_two(parcels: &Vec<usize>) -> Result<usize> {
+        best_entanglement(parcels, 4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_uneven_total() {
+        let e = best_entanglement(&[1, 2, 3, 5], 3).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Cannot split a total weight of 11 into 3 equal groups"
+        );
+    }
+}
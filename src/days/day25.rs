@@ -0,0 +1,88 @@
+use crate::solution::Solution;
+use crate::{parse_err, Result};
+
+/// Find the code at a given row and column of the diagonally-filled code sheet.
+fn code_at(target_row: usize, target_col: usize) -> usize {
+    let factor = 252533;
+    let modulus = 33554393;
+
+    let mut row = 1;
+    let mut col = 1;
+    let mut val: usize = 20151125;
+    let mut progress = 1;
+
+    while (row != target_row) || (col != target_col) {
+        //println!("{:5} {:5}: {}", row, col, val);
+        val = (val * factor) % modulus;
+
+        row -= 1;
+        col += 1;
+
+        if row == 0 {
+            progress += 1;
+            row = progress;
+            col = 1;
+        }
+    }
+
+    val
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (usize, usize);
+    type AnswerOne = usize;
+    type AnswerTwo = &'static str;
+
+    fn parse(input: &str) -> Result<(usize, usize)> {
+        // To continue, please consult the code grid in the manual.  Enter the code at row 3010, column 3019.
+        let tokens: Vec<_> = input
+            .split_whitespace()
+            .map(|t| t.trim_end_matches(&[',', '.'][..]))
+            .collect();
+
+        let value_after = |label: &str| -> Result<usize> {
+            let pos = tokens
+                .iter()
+                .position(|t| *t == label)
+                .ok_or_else(|| parse_err!("Missing {} in '{}'", label, input.trim()))?;
+
+            let value = tokens
+                .get(pos + 1)
+                .ok_or_else(|| parse_err!("Missing value for {}", label))?;
+
+            Ok(value.parse()?)
+        };
+
+        let (row, col) = (value_after("row")?, value_after("column")?);
+        if row == 0 || col == 0 {
+            return Err(parse_err!("Rows and columns start at 1"));
+        }
+
+        Ok((row, col))
+    }
+
+    fn part_one((row, col): &(usize, usize)) -> Result<usize> {
+        Ok(code_at(*row, *col))
+    }
+
+    fn part_two(_input: &(usize, usize)) -> Result<&'static str> {
+        // there is no second puzzle on the last day
+        Ok("Merry Christmas!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_code_sheet() {
+        assert_eq!(code_at(1, 1), 20151125);
+        assert_eq!(code_at(2, 1), 31916031);
+        assert_eq!(code_at(1, 2), 18749137);
+        assert_eq!(code_at(6, 6), 27995004);
+    }
+}
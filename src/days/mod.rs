@@ -0,0 +1,180 @@
+use crate::solution::{solve, solve_stream, Report};
+use crate::util::{read_stdin_to_string, read_to_string};
+use crate::{parse_err, state_err, Error, Result};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// The first day of the calendar.
+pub const FIRST_DAY: usize = 1;
+
+/// The last day of the calendar.
+pub const LAST_DAY: usize = 25;
+
+/// Get the path to the checked-in puzzle input of a day.
+pub fn input_path(day: usize) -> String {
+    format!("data/day{:02}/input", day)
+}
+
+/// Where to read the puzzle input of a day from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The checked-in puzzle input of the day, see `input_path`.
+    Default,
+
+    /// A file identified by a path.
+    File { path: String },
+
+    /// Standard input.
+    Stdin,
+}
+
+impl std::str::FromStr for InputSource {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "" => Err(parse_err!("Empty input path")),
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::File { path: s.to_owned() }),
+        }
+    }
+}
+
+impl InputSource {
+    /// Describe where the input of a day is read from, with `-` standing for standard input.
+    pub fn describe(&self, day: usize) -> String {
+        match self {
+            InputSource::Default => input_path(day),
+            InputSource::File { path } => path.to_owned(),
+            InputSource::Stdin => "-".to_owned(),
+        }
+    }
+
+    /// Get the path of the file the input of a day is read from, or None for standard input.
+    pub fn path(&self, day: usize) -> Option<String> {
+        match self {
+            InputSource::Default => Some(input_path(day)),
+            InputSource::File { path } => Some(path.to_owned()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// Read the puzzle input of a day.
+    pub fn read(&self, day: usize) -> Result<String> {
+        match self {
+            InputSource::Default => read_to_string(&input_path(day)),
+            InputSource::File { path } => read_to_string(path),
+            InputSource::Stdin => read_stdin_to_string(),
+        }
+    }
+}
+
+/// Read the puzzle input of a day and solve both parts, reporting errors against the input source.
+///
+/// Days whose solvers only need one character at a time read input files as a stream, so
+/// their inputs need not fit in memory.
+pub fn run_from(day: usize, source: &InputSource) -> Result<Report> {
+    if let Some(path) = source.path(day) {
+        if let Some(report) = run_stream(day, &path) {
+            return report.map_err(|e| e.in_file(&path));
+        }
+    }
+
+    let input = source.read(day)?;
+    run(day, &input).map_err(|e| e.in_file(&source.describe(day)))
+}
+
+/// Solve both parts of the puzzle of a day by streaming its input from a file, or get None if
+/// the day cannot be solved from a stream.
+fn run_stream(day: usize, path: &str) -> Option<Result<Report>> {
+    match day {
+        1 => Some(solve_stream(
+            path,
+            day01::final_floor,
+            day01::basement_position,
+        )),
+        3 => Some(solve_stream(
+            path,
+            |chars| Ok(day03::walk(1, chars)?.len()),
+            |chars| Ok(day03::walk(2, chars)?.len()),
+        )),
+        _ => None,
+    }
+}
+
+/// Solve both parts of the puzzle of a day for a given raw puzzle input.
+pub fn run(day: usize, input: &str) -> Result<Report> {
+    match day {
+        1 => solve::<day01::Day01>(input),
+        2 => solve::<day02::Day02>(input),
+        3 => solve::<day03::Day03>(input),
+        4 => solve::<day04::Day04>(input),
+        5 => solve::<day05::Day05>(input),
+        6 => solve::<day06::Day06>(input),
+        7 => solve::<day07::Day07>(input),
+        8 => solve::<day08::Day08>(input),
+        9 => solve::<day09::Day09>(input),
+        10 => solve::<day10::Day10>(input),
+        11 => solve::<day11::Day11>(input),
+        12 => solve::<day12::Day12>(input),
+        13 => solve::<day13::Day13>(input),
+        14 => solve::<day14::Day14>(input),
+        15 => solve::<day15::Day15>(input),
+        16 => solve::<day16::Day16>(input),
+        17 => solve::<day17::Day17>(input),
+        18 => solve::<day18::Day18>(input),
+        19 => solve::<day19::Day19>(input),
+        20 => solve::<day20::Day20>(input),
+        21 => solve::<day21::Day21>(input),
+        22 => solve::<day22::Day22>(input),
+        23 => solve::<day23::Day23>(input),
+        24 => solve::<day24::Day24>(input),
+        25 => solve::<day25::Day25>(input),
+        _ => Err(state_err!("No solution for day {}", day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_run_stream() -> Result<()> {
+        let input = "data/day03/input";
+        let streamed = run_stream(3, input).unwrap()?;
+        assert_eq!(streamed.answers, run(3, &read_to_string(input)?)?.answers);
+        assert!(run_stream(4, input).is_none());
+
+        let missing = InputSource::File {
+            path: "data/no_such_file".to_owned(),
+        };
+        let e = run_from(1, &missing).unwrap_err();
+        assert!(e.to_string().starts_with("data/no_such_file: "));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+/// Create an `Error::Parse` from a format string, for input that cannot be understood.
+#[macro_export]
+macro_rules! parse_err {
+    ($($arg:tt)*) => {
+        $crate::Error::parse(format!($($arg)*))
+    };
+}
+
+/// Create an `Error::InvalidState` from a format string, for computations that cannot continue.
+#[macro_export]
+macro_rules! state_err {
+    ($($arg:tt)*) => {
+        $crate::Error::invalid_state(format!($($arg)*))
+    };
+}
+
+/// Where in an input a parse error occurred. All positions are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Location {
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let mut parts = Vec::new();
+        if let Some(file) = &self.file {
+            parts.push(file.to_owned());
+        }
+        if let Some(line) = self.line {
+            parts.push(line.to_string());
+        }
+        match (self.line, self.column) {
+            (Some(_), Some(column)) => parts.push(column.to_string()),
+            // without a line, a bare number would read as a line number
+            (None, Some(column)) => parts.push(format!("col {}", column)),
+            _ => {}
+        }
+
+        write!(f, "{}", parts.join(":"))
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// Reading a file or stream failed.
+    Io {
+        path: Option<String>,
+        source: std::io::Error,
+    },
+
+    /// The input could not be understood.
+    Parse { message: String, location: Location },
+
+    /// A computation reached a state it cannot continue from.
+    InvalidState { message: String },
+}
+
+impl Error {
+    pub fn parse(message: String) -> Self {
+        Error::Parse {
+            message,
+            location: Location::default(),
+        }
+    }
+
+    pub fn invalid_state(message: String) -> Self {
+        Error::InvalidState { message }
+    }
+
+    /// Attach the file an error occurred in, unless it is already known.
+    pub fn in_file(mut self, path: &str) -> Self {
+        match &mut self {
+            Error::Io { path: p @ None, .. } => *p = Some(path.to_owned()),
+            Error::Parse { location, .. } if location.file.is_none() => {
+                location.file = Some(path.to_owned())
+            }
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Attach the 1-based line number and the text of the line a parse error occurred on, unless
+    /// they are already known.
+    pub fn on_line(mut self, line: usize, snippet: &str) -> Self {
+        if let Error::Parse { location, .. } = &mut self {
+            if location.line.is_none() {
+                location.line = Some(line);
+                location.snippet = Some(snippet.to_owned());
+            }
+        }
+
+        self
+    }
+
+    /// Attach the 1-based column a parse error occurred at, unless it is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        if let Error::Parse { location, .. } = &mut self {
+            if location.column.is_none() {
+                location.column = Some(column);
+            }
+        }
+
+        self
+    }
+
+    /// Move the column of a parse error right, for errors from parsing only part of a line that
+    /// starts at a 1-based column.
+    pub fn within_column(mut self, start: usize) -> Self {
+        if let Error::Parse { location, .. } = &mut self {
+            location.column = Some(location.column.unwrap_or(1) + start - 1);
+        }
+
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            Error::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "{}: {}", path, source),
+            Error::Io { path: None, source } => write!(f, "{}", source),
+            Error::Parse { message, location } => {
+                if location == &Location::default() {
+                    return write!(f, "{}", message);
+                }
+
+                write!(f, "{}: {}", location, message)?;
+
+                if let Some(snippet) = &location.snippet {
+                    write!(f, "\n  | {}", snippet)?;
+                    if let Some(column) = location.column {
+                        let indent: String = snippet
+                            .chars()
+                            .take(column.saturating_sub(1))
+                            .map(|c| if c == '\t' { '\t' } else { ' ' })
+                            .collect();
+                        write!(f, "\n  | {}^", indent)?;
+                    }
+                }
+
+                Ok(())
+            }
+            Error::InvalidState { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(source: std::io::Error) -> Self {
+        Error::Io { path: None, source }
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(e: std::num::ParseIntError) -> Self {
+        Error::parse(format!("Invalid integer: {}", e))
+    }
+}
+
+impl From<std::string::FromUtf8Error> for Error {
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        Error::parse(format!("Invalid UTF-8: {}", e))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        if e.is_io() {
+            return Error::Io {
+                path: None,
+                source: e.into(),
+            };
+        }
+
+        let location = Location {
+            line: Some(e.line()).filter(|l| *l > 0),
+            column: Some(e.column()).filter(|c| *c > 0),
+            ..Default::default()
+        };
+
+        // the location is reported separately
+        let message = e.to_string();
+        let suffix = format!(" at line {} column {}", e.line(), e.column());
+        let message = message.trim_end_matches(&suffix[..]);
+
+        Error::Parse {
+            message: format!("Invalid JSON: {}", message),
+            location,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn test_location_display() {
+        let e = parse_err!("Unexpected '{}'", "x")
+            .at_column(3)
+            .on_line(2, "abx")
+            .in_file("data/input");
+
+        assert_eq!(
+            e.to_string(),
+            "data/input:2:3: Unexpected 'x'\n  | abx\n  |   ^"
+        );
+
+        let e = parse_err!("Unexpected 'x'").in_file("-");
+        assert_eq!(e.to_string(), "-: Unexpected 'x'");
+
+        let e = parse_err!("Unexpected 'x'").at_column(16);
+        assert_eq!(e.to_string(), "col 16: Unexpected 'x'");
+
+        let e = state_err!("Stuck").in_file("data/input");
+        assert_eq!(e.to_string(), "Stuck");
+    }
+}
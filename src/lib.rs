@@ -1,6 +1,13 @@
-pub use failure::{format_err, Error};
+mod error;
+
+pub use error::{Error, Location};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+pub mod answers;
+pub mod bench;
 pub mod board;
+pub mod days;
+pub mod pattern;
+pub mod solution;
 pub mod util;
@@ -0,0 +1,203 @@
+use crate::{parse_err, Error, Result};
+
+/// A piece of a pattern, either text that has to appear verbatim or a named field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Field(String),
+}
+
+/// A template for lines of text, consisting of literal text and named `{field}` placeholders.
+///
+/// A field matches everything up to the first occurrence of the literal text following it, or the
+/// rest of the line if it is the last piece of the pattern. Use `{{` and `}}` for literal braces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pieces: Vec<Piece>,
+}
+
+/// The fields extracted from a line that matched a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'a> {
+    /// The name, text and 1-based column of every field, in the order they appear.
+    fields: Vec<(String, &'a str, usize)>,
+}
+
+impl std::str::FromStr for Pattern {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Pattern::new(s)
+    }
+}
+
+impl Pattern {
+    pub fn new(template: &str) -> Result<Pattern> {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    let mut closed = false;
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        name.push(c);
+                    }
+
+                    if !closed || name.is_empty() || name.contains('{') {
+                        return Err(parse_err!("Invalid field name in pattern '{}'", template));
+                    }
+
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    } else if let Some(Piece::Field(_)) = pieces.last() {
+                        return Err(parse_err!(
+                            "Fields must be separated by text in pattern '{}'",
+                            template
+                        ));
+                    }
+
+                    pieces.push(Piece::Field(name));
+                }
+                '}' => return Err(parse_err!("Unmatched '}}' in pattern '{}'", template)),
+                _ => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+
+        Ok(Pattern { pieces })
+    }
+
+    /// Match a line against the pattern and extract its fields.
+    ///
+    /// Errors name the literal text that was expected and the column it was expected at.
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Captures<'a>> {
+        let column = |pos: usize| line[..pos].chars().count() + 1;
+
+        let mut fields = Vec::new();
+        let mut pos = 0;
+
+        for (i, piece) in self.pieces.iter().enumerate() {
+            match piece {
+                Piece::Literal(literal) => {
+                    if !line[pos..].starts_with(&literal[..]) {
+                        return Err(parse_err!("Expected '{}'", literal).at_column(column(pos)));
+                    }
+                    pos += literal.len();
+                }
+                Piece::Field(name) => {
+                    let end = match self.pieces.get(i + 1) {
+                        Some(Piece::Literal(next)) => {
+                            pos + line[pos..].find(&next[..]).ok_or_else(|| {
+                                parse_err!("Expected '{}' after field '{}'", next, name)
+                                    .at_column(column(pos))
+                            })?
+                        }
+                        _ => line.len(),
+                    };
+
+                    if end == pos {
+                        return Err(parse_err!("Empty field '{}'", name).at_column(column(pos)));
+                    }
+
+                    fields.push((name.to_owned(), &line[pos..end], column(pos)));
+                    pos = end;
+                }
+            }
+        }
+
+        if pos != line.len() {
+            return Err(parse_err!("Unexpected trailing text").at_column(column(pos)));
+        }
+
+        Ok(Captures { fields })
+    }
+}
+
+impl<'a> Captures<'a> {
+    fn field(&self, name: &str) -> Result<&(String, &'a str, usize)> {
+        self.fields
+            .iter()
+            .find(|(n, _, _)| n == name)
+            .ok_or_else(|| parse_err!("No field '{}' in pattern", name))
+    }
+
+    /// Get the text of a field.
+    pub fn get(&self, name: &str) -> Result<&'a str> {
+        Ok(self.field(name)?.1)
+    }
+
+    /// Get the 1-based column a field starts at, e.g. to point errors about its value there.
+    pub fn column(&self, name: &str) -> Result<usize> {
+        Ok(self.field(name)?.2)
+    }
+
+    /// Parse the text of a field, annotating errors with the column the field starts at.
+    pub fn parse<T>(&self, name: &str) -> Result<T>
+    where
+        T: std::str::FromStr,
+        Error: From<T::Err>,
+    {
+        let (_, text, column) = self.field(name)?;
+        text.parse().map_err(|e| Error::from(e).at_column(*column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_pattern() -> Result<()> {
+        let p = Pattern::new("{name} can fly {speed} km/s, {{ok}}")?;
+
+        let c = p.captures("Dancer can fly 27 km/s, {ok}")?;
+        assert_eq!(c.get("name")?, "Dancer");
+        assert_eq!(c.parse::<usize>("speed")?, 27);
+
+        let e = p.captures("Dancer can run 27 km/s, {ok}").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "col 1: Expected ' can fly ' after field 'name'"
+        );
+
+        let e = p.captures("Dancer can fly 27 km/s; {ok}").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "col 16: Expected ' km/s, {ok}' after field 'speed'"
+        );
+
+        let e = Pattern::new("Sue {n}:")?.captures("Bob 1:").unwrap_err();
+        assert_eq!(e.to_string(), "col 1: Expected 'Sue '");
+
+        let e = p.captures("Dancer can fly x km/s, {ok}")?;
+        let e = e.parse::<usize>("speed").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "col 16: Invalid integer: invalid digit found in string"
+        );
+
+        assert!(p.captures("Dancer can fly 27 km/s, {ok} and more").is_err());
+        assert!(Pattern::new("{a}{b}").is_err());
+        assert!(Pattern::new("{a").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::util::stream_chars;
+use crate::{parse_err, Error, Result};
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+/// A solver for the puzzle of one day, split into parsing the input and solving both parts.
+pub trait Solution {
+    /// The parsed representation of the puzzle input, shared by both parts.
+    type Input;
+
+    /// The answer to the first part of the puzzle.
+    type AnswerOne: std::fmt::Display;
+
+    /// The answer to the second part of the puzzle.
+    type AnswerTwo: std::fmt::Display;
+
+    /// Parse the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Solve the first part of the puzzle.
+    fn part_one(input: &Self::Input) -> Result<Self::AnswerOne>;
+
+    /// Solve the second part of the puzzle.
+    fn part_two(input: &Self::Input) -> Result<Self::AnswerTwo>;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(parse_err!("Invalid part: '{}'", s)),
+        }
+    }
+}
+
+/// The answers to both parts of a puzzle, rendered as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: String,
+    pub part_two: String,
+}
+
+impl Answers {
+    /// Get the answer to one part of the puzzle.
+    pub fn get(&self, part: Part) -> &str {
+        match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        }
+    }
+}
+
+/// How long parsing and solving each part of a puzzle took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+/// The answers to a puzzle together with the time it took to find them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+impl Timings {
+    /// Get the time it took to solve one part of the puzzle.
+    pub fn get(&self, part: Part) -> Duration {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+impl Report {
+    /// Describe the answer to each part as a JSON record for consumption by other tools.
+    pub fn to_json(&self, day: usize, input: &str) -> Vec<Value> {
+        Part::ALL
+            .iter()
+            .map(|part| {
+                json!({
+                    "day": day,
+                    "part": part.number(),
+                    "answer": self.answers.get(*part),
+                    "duration_ns": self.timings.get(*part).as_nanos() as u64,
+                    "parse_duration_ns": self.timings.parse.as_nanos() as u64,
+                    "input": input,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Parse the raw puzzle input and solve both parts of a puzzle, timing each step.
+pub fn solve<S: Solution>(input: &str) -> Result<Report> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part_one = S::part_one(&input)?.to_string();
+    let part_one_time = start.elapsed();
+
+    let start = Instant::now();
+    let part_two = S::part_two(&input)?.to_string();
+    let part_two_time = start.elapsed();
+
+    Ok(Report {
+        answers: Answers { part_one, part_two },
+        timings: Timings {
+            parse,
+            part_one: part_one_time,
+            part_two: part_two_time,
+        },
+    })
+}
+
+/// A lazily read stream of characters, e.g. from `util::stream_chars`.
+pub type CharStream = Box<dyn Iterator<Item = Result<char>>>;
+
+/// Solve both parts of a puzzle whose solvers consume the input as a stream of characters,
+/// reading a file, identified by a path, once per part instead of holding it in memory.
+pub fn solve_stream<A: std::fmt::Display, B: std::fmt::Display>(
+    path: &str,
+    part_one: fn(CharStream) -> Result<A>,
+    part_two: fn(CharStream) -> Result<B>,
+) -> Result<Report> {
+    let start = Instant::now();
+    let part_one = part_one(Box::new(stream_chars(path)))?.to_string();
+    let part_one_time = start.elapsed();
+
+    let start = Instant::now();
+    let part_two = part_two(Box::new(stream_chars(path)))?.to_string();
+    let part_two_time = start.elapsed();
+
+    Ok(Report {
+        answers: Answers { part_one, part_two },
+        timings: Timings {
+            parse: Duration::default(),
+            part_one: part_one_time,
+            part_two: part_two_time,
+        },
+    })
+}
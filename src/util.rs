@@ -1,13 +1,29 @@
-use crate::Result;
+use crate::{parse_err, Error, Result};
+use std::convert::TryInto;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 
+fn open(path: &str) -> Result<File> {
+    File::open(path).map_err(|e| Error::from(e).in_file(path))
+}
+
 /// Read a whole file, identified by a path, to a String.
 pub fn read_to_string(path: &str) -> Result<String> {
-    let mut f = File::open(path)?;
+    let mut f = open(path)?;
 
     let mut buf = Vec::new();
-    f.read_to_end(&mut buf)?;
+    f.read_to_end(&mut buf)
+        .map_err(|e| Error::from(e).in_file(path))?;
+
+    let buf = String::from_utf8(buf).map_err(|e| Error::from(e).in_file(path))?;
+
+    Ok(buf)
+}
+
+/// Read all of standard input to a String.
+pub fn read_stdin_to_string() -> Result<String> {
+    let mut buf = Vec::new();
+    std::io::stdin().read_to_end(&mut buf)?;
 
     let buf = String::from_utf8(buf)?;
 
@@ -16,13 +32,160 @@ pub fn read_to_string(path: &str) -> Result<String> {
 
 /// Read a whole file, identified by a path, to a list of Strings, one per line.
 pub fn read_to_lines(path: &str) -> Result<Vec<String>> {
-    let f = File::open(path)?;
+    let f = open(path)?;
     let br = BufReader::new(f);
 
-    br.lines().map(|l| Ok(l?.to_owned())).collect()
+    br.lines()
+        .map(|l| l.map_err(|e| Error::from(e).in_file(path)))
+        .collect()
+}
+
+/// Parse a text to a list of parsed representations, one per line.
+///
+/// Parse errors are annotated with the number and text of the line they occurred on.
+pub fn parse_lines<T, C: std::iter::FromIterator<T>, F: Fn(&str) -> Result<T>>(
+    text: &str,
+    parser: F,
+) -> Result<C> {
+    text.lines()
+        .enumerate()
+        .map(|(i, l)| parser(l).map_err(|e| e.on_line(i + 1, l)))
+        .collect()
+}
+
+/// A block of consecutive non-blank lines of a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// The 1-based number of the first line of the section within the whole text.
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    /// Parse the section to a list of parsed representations, one per line.
+    ///
+    /// Parse errors are annotated with the number of the line within the whole text and its text.
+    pub fn parse_lines<T, C: std::iter::FromIterator<T>, F: Fn(&str) -> Result<T>>(
+        &self,
+        parser: F,
+    ) -> Result<C> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| parser(l).map_err(|e| e.on_line(self.first_line + i, l)))
+            .collect()
+    }
+
+    /// Parse a section that consists of exactly one line.
+    pub fn parse_line<T, F: FnOnce(&str) -> Result<T>>(&self, parser: F) -> Result<T> {
+        if self.lines.len() != 1 {
+            return Err(
+                parse_err!("Expected a single line, found {} lines", self.lines.len())
+                    .on_line(self.first_line, self.lines[0]),
+            );
+        }
+
+        parser(self.lines[0]).map_err(|e| e.on_line(self.first_line, self.lines[0]))
+    }
+}
+
+/// Split a text into sections separated by one or more blank lines.
+///
+/// Lines consisting only of whitespace count as blank.
+pub fn sections(text: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current: Option<Section> = None;
+
+    for (i, l) in text.lines().enumerate() {
+        if l.trim().is_empty() {
+            sections.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Section {
+                    first_line: i + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(l);
+        }
+    }
+    sections.extend(current);
+
+    sections
+}
+
+/// Split a text into exactly `N` sections separated by blank lines, see `sections`.
+///
+/// An error for too many sections points at the first line of the first unexpected one.
+pub fn split_sections<const N: usize>(text: &str) -> Result<[Section<'_>; N]> {
+    let sections = sections(text);
+    let found = sections.len();
+
+    if let Some(extra) = sections.get(N) {
+        return Err(parse_err!(
+            "Expected {} sections separated by blank lines, found {}",
+            N,
+            found
+        )
+        .on_line(extra.first_line, extra.lines[0]));
+    }
+
+    sections.try_into().map_err(|_| {
+        parse_err!(
+            "Expected {} sections separated by blank lines, found {}",
+            N,
+            found
+        )
+    })
+}
+
+/// Parse lines of `Name: value` stats, such as `Hit Points: 109`, into the values of the given
+/// names in the same order. Blank lines are skipped.
+///
+/// Every name has to occur, and errors for malformed lines or unknown names point at the line.
+pub fn parse_stats<T, const N: usize>(text: &str, names: [&str; N]) -> Result<[T; N]>
+where
+    T: std::str::FromStr,
+    Error: From<T::Err>,
+{
+    let mut values: Vec<Option<T>> = names.iter().map(|_| None).collect();
+
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| parse_err!("Invalid stat line").on_line(i + 1, line))?;
+
+        let k = names
+            .iter()
+            .position(|n| *n == name.trim())
+            .ok_or_else(|| parse_err!("Unknown stat: '{}'", name.trim()).on_line(i + 1, line))?;
+
+        values[k] = Some(
+            value
+                .trim()
+                .parse()
+                .map_err(|e| Error::from(e).on_line(i + 1, line))?,
+        );
+    }
+
+    let values = values
+        .into_iter()
+        .zip(names.iter())
+        .map(|(v, name)| v.ok_or_else(|| parse_err!("Missing stat: '{}'", name)))
+        .collect::<Result<Vec<T>>>()?;
+
+    values
+        .try_into()
+        .map_err(|_| parse_err!("Expected {} stats", N))
 }
 
 /// Read a whole file, identified by a path, to a list of parsed representations, one per line.
+///
+/// Parse errors are annotated with the path, line number and text of the line they occurred on.
 pub fn read_to_parsed_lines<
     T,
     C: std::iter::FromIterator<T>,
@@ -31,8 +194,239 @@ pub fn read_to_parsed_lines<
     path: &str,
     parser: &F,
 ) -> Result<C> {
-    let f = File::open(path)?;
+    let f = open(path)?;
     let br = BufReader::new(f);
 
-    br.lines().map(move |l| parser(&l?)).collect()
+    br.lines()
+        .enumerate()
+        .map(move |(i, l)| {
+            let l = l?;
+            parser(&l).map_err(|e| e.on_line(i + 1, &l))
+        })
+        .map(|r| r.map_err(|e| e.in_file(path)))
+        .collect()
+}
+
+/// Lazily read a file, identified by a path, as parsed representations, one per line.
+///
+/// Only one line is held in memory at a time. A file that cannot be opened yields a single error.
+/// Parse errors are annotated like in `read_to_parsed_lines`.
+pub fn parsed_lines<T, F: Fn(&str) -> Result<T>>(
+    path: &str,
+    parser: F,
+) -> impl Iterator<Item = Result<T>> {
+    let path = path.to_owned();
+
+    let (lines, error) = match open(&path) {
+        Ok(f) => (Some(BufReader::new(f).lines()), None),
+        Err(e) => (None, Some(e)),
+    };
+
+    error
+        .into_iter()
+        .map(Err)
+        .chain(lines.into_iter().flatten().enumerate().map(move |(i, l)| {
+            let l = l.map_err(|e| Error::from(e).in_file(&path))?;
+            parser(&l).map_err(|e| e.on_line(i + 1, &l).in_file(&path))
+        }))
+}
+
+/// Lazily read a file, identified by a path, byte by byte.
+pub fn stream_bytes(path: &str) -> impl Iterator<Item = Result<u8>> {
+    let path = path.to_owned();
+
+    let (bytes, error) = match open(&path) {
+        Ok(f) => (Some(BufReader::new(f).bytes()), None),
+        Err(e) => (None, Some(e)),
+    };
+
+    error.into_iter().map(Err).chain(
+        bytes
+            .into_iter()
+            .flatten()
+            .map(move |b| b.map_err(|e| Error::from(e).in_file(&path))),
+    )
+}
+
+/// Lazily read a file, identified by a path, character by character.
+pub fn stream_chars(path: &str) -> impl Iterator<Item = Result<char>> {
+    let path = path.to_owned();
+    Utf8Chars::new(stream_bytes(&path)).map(move |c| c.map_err(|e| e.in_file(&path)))
+}
+
+/// Lazily decode a stream of bytes, e.g. from standard input, character by character.
+pub fn read_chars<R: Read>(reader: R) -> impl Iterator<Item = Result<char>> {
+    Utf8Chars::new(BufReader::new(reader).bytes().map(|b| Ok(b?)))
+}
+
+/// Decodes UTF-8 characters from a stream of bytes without buffering more than one character.
+struct Utf8Chars<I> {
+    bytes: I,
+    offset: usize,
+}
+
+impl<I: Iterator<Item = Result<u8>>> Utf8Chars<I> {
+    fn new(bytes: I) -> Self {
+        Utf8Chars { bytes, offset: 0 }
+    }
+
+    fn invalid(&self, start: usize) -> Error {
+        parse_err!("Invalid UTF-8 sequence at byte offset {}", start)
+    }
+}
+
+impl<I: Iterator<Item = Result<u8>>> Iterator for Utf8Chars<I> {
+    type Item = Result<char>;
+
+    fn next(&mut self) -> Option<Result<char>> {
+        let start = self.offset;
+        let first = match self.bytes.next()? {
+            Ok(b) => b,
+            Err(e) => return Some(Err(e)),
+        };
+        self.offset += 1;
+
+        let width = match first {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => return Some(Err(self.invalid(start))),
+        };
+
+        let mut buf = [first, 0, 0, 0];
+        for b in buf.iter_mut().take(width).skip(1) {
+            *b = match self.bytes.next() {
+                Some(Ok(b)) => b,
+                Some(Err(e)) => return Some(Err(e)),
+                None => return Some(Err(self.invalid(start))),
+            };
+            self.offset += 1;
+        }
+
+        match std::str::from_utf8(&buf[..width]) {
+            Ok(s) => s.chars().next().map(Ok),
+            Err(_) => Some(Err(self.invalid(start))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_read_chars() -> Result<()> {
+        let chars: String = read_chars("a(ä)🎄".as_bytes()).collect::<Result<_>>()?;
+        assert_eq!(chars, "a(ä)🎄");
+
+        let invalid: Vec<Result<char>> = read_chars(&b"a\xc3("[..]).collect();
+        assert_eq!(invalid.len(), 2);
+        assert!(invalid[1].is_err());
+
+        Ok(())
+    }
+
+    /// Write a file to the temporary directory and return its path.
+    fn temp_file(name: &str, contents: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("aoc2015-{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path.to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn test_parsed_lines() {
+        let path = temp_file("parsed_lines", b"1\n2\nx\n4\n");
+
+        let values: Vec<Result<u8>> = parsed_lines(&path, |l| Ok(l.parse()?)).collect();
+        assert_eq!(values.len(), 4);
+        assert_eq!(values[1].as_ref().unwrap(), &2);
+        assert_eq!(
+            values[2].as_ref().unwrap_err().to_string(),
+            format!(
+                "{}:3: Invalid integer: invalid digit found in string\n  | x",
+                path
+            )
+        );
+        assert!(values[3].is_ok());
+
+        let missing: Vec<Result<u8>> =
+            parsed_lines("data/no_such_file", |l| Ok(l.parse()?)).collect();
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .starts_with("data/no_such_file: "));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_stream_file() -> Result<()> {
+        let path = temp_file("stream", "(ä)🎄".as_bytes());
+
+        let bytes: Vec<u8> = stream_bytes(&path).collect::<Result<_>>()?;
+        assert_eq!(bytes, "(ä)🎄".as_bytes());
+        let chars: String = stream_chars(&path).collect::<Result<_>>()?;
+        assert_eq!(chars, "(ä)🎄");
+
+        assert_eq!(stream_bytes("data/no_such_file").count(), 1);
+        let missing: Vec<Result<char>> = stream_chars("data/no_such_file").collect();
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].is_err());
+
+        std::fs::remove_file(path).unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_stats() -> Result<()> {
+        let text = "Hit Points: 109\n\nDamage: 8\nArmor: 2\n";
+        let [hp, damage, armor] = parse_stats::<i8, 3>(text, ["Hit Points", "Damage", "Armor"])?;
+        assert_eq!((hp, damage, armor), (109, 8, 2));
+
+        let e = parse_stats::<i8, 2>(text, ["Hit Points", "Damage"]).unwrap_err();
+        assert_eq!(e.to_string(), "4: Unknown stat: 'Armor'\n  | Armor: 2");
+        let e = parse_stats::<i8, 1>("Damage 8", ["Damage"]).unwrap_err();
+        assert_eq!(e.to_string(), "1: Invalid stat line\n  | Damage 8");
+        let e = parse_stats::<i8, 2>("Damage: 8", ["Hit Points", "Damage"]).unwrap_err();
+        assert_eq!(e.to_string(), "Missing stat: 'Hit Points'");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sections() -> Result<()> {
+        let text = "a => b\nc => d\n\n  \nabcd\n";
+
+        let [rules, molecule] = split_sections(text)?;
+        assert_eq!(rules.first_line, 1);
+        assert_eq!(rules.lines, vec!["a => b", "c => d"]);
+        assert_eq!(molecule.first_line, 5);
+        assert_eq!(molecule.parse_line(|l| Ok(l.len()))?, 4);
+
+        let lens: Vec<usize> = rules.parse_lines(|l| Ok(l.len()))?;
+        assert_eq!(lens, vec![6, 6]);
+
+        let e = rules
+            .parse_lines::<(), Vec<_>, _>(|l| match l {
+                "c => d" => Err(parse_err!("Bad rule")),
+                _ => Ok(()),
+            })
+            .unwrap_err();
+        assert_eq!(e.to_string(), "2: Bad rule\n  | c => d");
+
+        let e = split_sections::<1>(text).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "5: Expected 1 sections separated by blank lines, found 2\n  | abcd"
+        );
+        assert!(split_sections::<3>(text).is_err());
+        assert!(sections("\n\n").is_empty());
+
+        Ok(())
+    }
 }
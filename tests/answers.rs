@@ -0,0 +1,90 @@
+use aoc2015::answers::{check_day, example_inputs, read_examples, read_manifest, MANIFEST_PATH};
+use aoc2015::days::{FIRST_DAY, LAST_DAY};
+use aoc2015::solution::Part;
+use aoc2015::Result;
+
+/// Check the answers of a day for its puzzle input and for its worked examples, if it has any.
+fn check(day: usize) -> Result<()> {
+    let mut expected = read_manifest(MANIFEST_PATH)?;
+    expected.extend(read_examples(day)?);
+    let mismatches = check_day(&expected, day)?;
+
+    let report: Vec<String> = mismatches.iter().map(|m| m.to_string()).collect();
+    assert!(mismatches.is_empty(), "\n{}\n", report.join("\n"));
+
+    Ok(())
+}
+
+#[test]
+fn test_manifest_covers_all_days() -> Result<()> {
+    let manifest = read_manifest(MANIFEST_PATH)?;
+
+    for day in FIRST_DAY..=LAST_DAY {
+        for part in Part::ALL.iter() {
+            assert!(
+                manifest.iter().any(|e| e.day == day && e.part == *part),
+                "No expected answer for day {:02} part {}",
+                day,
+                part
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_every_example_has_answers() -> Result<()> {
+    for day in FIRST_DAY..=LAST_DAY {
+        let examples = read_examples(day)?;
+
+        for input in example_inputs(day)? {
+            assert!(
+                examples.iter().any(|e| e.input == input),
+                "No expected answer for example {}",
+                input
+            );
+        }
+    }
+
+    Ok(())
+}
+
+macro_rules! answer_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() -> Result<()> {
+                check($day)
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    test_day01: 1,
+    test_day02: 2,
+    test_day03: 3,
+    test_day04: 4,
+    test_day05: 5,
+    test_day06: 6,
+    test_day07: 7,
+    test_day08: 8,
+    test_day09: 9,
+    test_day10: 10,
+    test_day11: 11,
+    test_day12: 12,
+    test_day13: 13,
+    test_day14: 14,
+    test_day15: 15,
+    test_day16: 16,
+    test_day17: 17,
+    test_day18: 18,
+    test_day19: 19,
+    test_day20: 20,
+    test_day21: 21,
+    test_day22: 22,
+    test_day23: 23,
+    test_day24: 24,
+    test_day25: 25,
+}